@@ -16,10 +16,29 @@ fn main() {
 
     let mut data = Vec::new();
 
+    // Extensions exposed by the crate. Every entry point from these must be gated behind
+    // a check of `GLHF::supports`.
+    let extensions = [
+        "GL_EXT_base_instance",
+        "GL_EXT_disjoint_timer_query",
+        "GL_EXT_polygon_offset_clamp",
+        "GL_EXT_texture_norm16",
+        "GL_EXT_texture_sRGB_R8",
+        "GL_EXT_texture_sRGB_RG8",
+        "GL_KHR_robustness",
+        "GL_OES_viewport_array",
+    ];
+
     // Lol, to ask for GLES3 you say.. GLES2 version 3? weirmd
-    Registry::new(Api::Gles2, (3, 2), Profile::Core, Fallbacks::All, [])
-        .write_bindings(GlobalGenerator, &mut std::io::Cursor::new(&mut data))
-        .expect("failed to generate gl bindings");
+    Registry::new(
+        Api::Gles2,
+        (3, 2),
+        Profile::Core,
+        Fallbacks::All,
+        extensions,
+    )
+    .write_bindings(GlobalGenerator, &mut std::io::Cursor::new(&mut data))
+    .expect("failed to generate gl bindings");
 
     let data = String::from_utf8(data).expect("gl bindings are invalid utf8");
 
@@ -339,6 +339,7 @@ impl Window {
                     program,
                     glhf::program::ProgramShaders::Graphics {
                         vertex: &vertex,
+                        geometry: None,
                         fragment: &fragment,
                     },
                 )
@@ -391,6 +392,7 @@ impl Window {
                     program,
                     glhf::program::ProgramShaders::Graphics {
                         vertex: &vertex,
+                        geometry: None,
                         fragment: &fragment,
                     },
                 )
@@ -509,15 +511,9 @@ impl Window {
         let (vertices, indices) =
             load_obj(std::io::Cursor::new(include_bytes!("test.obj"))).unwrap();
 
-        // Generate unique buffer names.
-        let [vertex_buffer, index_buffer] = gl.new.buffers();
+        // Generate a unique buffer name.
+        let [index_buffer] = gl.new.buffers();
 
-        // Bulk upload our scene data
-        let vbo = gl.buffer.array.bind(&vertex_buffer).data(
-            bytemuck::cast_slice(&vertices),
-            glhf::buffer::usage::Frequency::Static,
-            glhf::buffer::usage::Access::Draw,
-        );
         // Index (or, in gl terms, "element") buffer.
         gl.buffer.element_array.bind(&index_buffer).data(
             bytemuck::cast_slice(&indices),
@@ -525,20 +521,13 @@ impl Window {
             glhf::buffer::usage::Access::Draw,
         );
 
-        // Vertex arrays store references to (potentially many) "Array" buffers,
-        // specifying the layout of vertex attributes within them.
-        let [vao] = gl.new.vertex_arrays();
-
         // Calculate the distance between consecutive attributes.
         // (e.g., the distance from one `Vertex.pos` to the next.)
         let stride = std::mem::size_of::<Vertex>().try_into().unwrap();
 
-        // Set up the vertex specification.
-        gl.vertex_array
-            .bind(&vao)
-            .attribute(
-                // Read from our vertex buffer,
-                vbo,
+        // Describe the vertex specification.
+        let attributes = [
+            (
                 // Vertex shader location zero,
                 0,
                 vertex_array::Attribute {
@@ -550,12 +539,9 @@ impl Window {
                     // Offset from the beginning of the buffer by...
                     offset: std::mem::offset_of!(Vertex, pos),
                 },
-                // Enable fetching for this attribute.
-                Some(true),
-            )
-            .attribute(
+            ),
+            (
                 // Again, for the normals.
-                vbo,
                 1,
                 vertex_array::Attribute {
                     components: vertex_array::Components::Vec3,
@@ -564,9 +550,14 @@ impl Window {
                     // Except this time the offset differs.
                     offset: std::mem::offset_of!(Vertex, normal),
                 },
-                // Enable fetching for this attribute.
-                Some(true),
-            );
+            ),
+        ];
+        let layout = vertex_array::VertexLayout::<Vertex>::new(&attributes);
+
+        // Bulk upload our vertices into a new buffer. Vertex arrays store references to
+        // (potentially many) "Array" buffers, specifying the layout of vertex attributes
+        // within them - this creates one reading each attribute of `layout` from the buffer.
+        let (vertex_buffer, vao) = gl.upload_mesh(&vertices, &layout);
 
         // We don't need any cleanup, since all our resources last for the lifetime of the program.
         // This here is actually a resource leak! Handles do not implement any kind of resource
@@ -644,11 +635,10 @@ impl Window {
         };
         unsafe {
             // Draw our indexed mesh.
-            gl.draw.elements(
+            gl.draw.elements_once(
                 glhf::draw::Topology::Triangles,
                 glhf::draw::ElementType::U16,
                 0..self.num_indices,
-                1,
                 draw_info,
             )
         };
@@ -679,11 +669,10 @@ impl Window {
             vertex_array,
         };
         unsafe {
-            gl.draw.elements(
+            gl.draw.elements_once(
                 glhf::draw::Topology::Triangles,
                 glhf::draw::ElementType::U16,
                 0..self.num_indices,
-                1,
                 draw_info,
             )
         };
@@ -0,0 +1,91 @@
+//! Scoped bulk deletion of objects.
+//!
+//! This is not reference counting - objects are never deleted while a handle to them exists
+//! outside of the arena, because moving a handle into the arena is the only way to schedule
+//! its deletion.
+use alloc::vec::Vec;
+
+use crate::{
+    gl::{self, types::GLuint},
+    sealed::ObjectKind,
+    ThinGLObject, GLHF,
+};
+
+/// Owns objects registered into it, deleting all of them when dropped.
+///
+/// Created by [`GLHF::arena`], which it borrows for its whole lifetime and dereferences to, such
+/// that it may be used in place of the [`GLHF`] within a scope. Deletion may unbind objects,
+/// so no `Active` marker can outlive the arena.
+///
+/// Objects are batched by kind, with a single `glDelete*` call per kind where the GL allows it.
+pub struct Arena<'gl> {
+    // Holding the GLHF ensures `Self::drop` has safe access to gl calls due to safety
+    // precondition of `crate::GLHF`, and that no `Active` outlives the deletion.
+    gl: &'gl mut GLHF,
+    names: Vec<(ObjectKind, GLuint)>,
+}
+impl<'gl> Arena<'gl> {
+    pub(crate) fn new(gl: &'gl mut GLHF) -> Self {
+        Self {
+            gl,
+            names: Vec::new(),
+        }
+    }
+    /// Take ownership of `object`, deleting it when the arena is dropped.
+    ///
+    /// Objects in use when the arena drops follow the usual deletion rules of the GL. For
+    /// instance, a buffer still referenced by a vertex array stays alive until that vertex array
+    /// is deleted, as do shaders attached to a program.
+    pub fn adopt<Object: ThinGLObject>(&mut self, object: Object) -> &mut Self {
+        let name = object.into_name().get();
+        // The handle is gone, so the name is as good as deleted already.
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::untrack::<Object>(&[name]);
+        self.names.push((Object::KIND, name));
+        self
+    }
+    /// The number of objects to be deleted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+    /// Whether no objects have been adopted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+impl core::ops::Deref for Arena<'_> {
+    type Target = GLHF;
+    fn deref(&self) -> &Self::Target {
+        self.gl
+    }
+}
+impl core::ops::DerefMut for Arena<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.gl
+    }
+}
+impl Drop for Arena<'_> {
+    fn drop(&mut self) {
+        self.names.sort_unstable();
+        for batch in self.names.chunk_by(|a, b| a.0 == b.0) {
+            let kind = batch[0].0;
+            let names: Vec<GLuint> = batch.iter().map(|&(_, name)| name).collect();
+            let len = names.len().try_into().unwrap();
+            unsafe {
+                match kind {
+                    ObjectKind::Buffer => gl::DeleteBuffers(len, names.as_ptr()),
+                    ObjectKind::Framebuffer => gl::DeleteFramebuffers(len, names.as_ptr()),
+                    ObjectKind::Query => gl::DeleteQueries(len, names.as_ptr()),
+                    ObjectKind::Renderbuffer => gl::DeleteRenderbuffers(len, names.as_ptr()),
+                    ObjectKind::Texture => gl::DeleteTextures(len, names.as_ptr()),
+                    ObjectKind::VertexArray => gl::DeleteVertexArrays(len, names.as_ptr()),
+                    // No bulk deletion for these.
+                    ObjectKind::Program => names.iter().for_each(|&name| gl::DeleteProgram(name)),
+                    ObjectKind::Shader => names.iter().for_each(|&name| gl::DeleteShader(name)),
+                }
+            }
+        }
+    }
+}
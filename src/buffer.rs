@@ -121,4 +121,6 @@ pub struct Buffer(pub(crate) NonZeroName);
 impl crate::sealed::Sealed for Buffer {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for Buffer {}
+unsafe impl crate::ThinGLObject for Buffer {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Buffer;
+}
@@ -18,18 +18,135 @@ type ActiveDrawFramebuffer<Defaultness> =
 
 use super::{gl, GLEnum, NotSync};
 
+/// How consecutive vertices are assembled into primitives.
+///
+/// Vertices which do not complete a primitive are silently ignored by the GL - for instance, a
+/// [`LineLoop`](Self::LineLoop) of one vertex draws nothing. Every topology may be instanced,
+/// each instance being assembled separately.
 #[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Topology {
+    /// Each vertex is rasterized as a square of side `gl_PointSize` pixels, as written by the vertex
+    /// shader and clamped to [`State::point_size_range`](crate::state::State::point_size_range).
+    /// Within the square, `gl_PointCoord` runs from `(0, 0)` at the
+    /// [upper-left](crate::state::State::point_coord_origin) to `(1, 1)`.
     Points = gl::POINTS,
+    /// A line through every vertex in order. Requires at least two vertices.
     LineStrip = gl::LINE_STRIP,
+    /// As [`Self::LineStrip`], with the last vertex joined back to the first. Requires at least
+    /// two vertices.
     LineLoop = gl::LINE_LOOP,
+    /// A separate line for each pair of vertices. Requires a multiple of two vertices.
     Lines = gl::LINES,
+    /// A triangle for each vertex after the second, sharing the previous two. Requires at least
+    /// three vertices.
     TriangleStrip = gl::TRIANGLE_STRIP,
+    /// A triangle for each vertex after the second, sharing the previous one and the first.
+    /// Requires at least three vertices.
     TriangleFan = gl::TRIANGLE_FAN,
+    /// A separate triangle for each three vertices. Requires a multiple of three vertices.
     Triangles = gl::TRIANGLES,
+    /// Groups of [`State::patch_vertices`](crate::state::State::patch_vertices) vertices,
+    /// consumed by a tessellation control shader. Requires GLES 3.2.
+    Patches = gl::PATCHES,
 }
 // Safety: is repr(u32) enum.
 unsafe impl GLEnum for Topology {}
+impl Topology {
+    /// The fewest vertices which form a primitive, fewer draw nothing.
+    ///
+    /// `None` for [`Self::Patches`], where this is [`State::patch_vertices`](crate::state::State::patch_vertices).
+    #[must_use]
+    pub fn min_vertices(&self) -> Option<usize> {
+        match self {
+            Self::Points => Some(1),
+            Self::LineStrip | Self::LineLoop | Self::Lines => Some(2),
+            Self::TriangleStrip | Self::TriangleFan | Self::Triangles => Some(3),
+            Self::Patches => None,
+        }
+    }
+    /// Panic if `count` vertices would leave some unused, as that is almost certainly a mistake.
+    #[cfg(debug_assertions)]
+    fn assert_vertex_count(&self, count: usize) {
+        let too_few = self.min_vertices().is_some_and(|min| count < min);
+        let leftover = match self {
+            Self::Lines => count % 2,
+            Self::Triangles => count % 3,
+            _ => 0,
+        };
+        assert!(
+            !too_few && leftover == 0,
+            "{count} vertices do not form whole {self:?} primitives"
+        );
+    }
+}
+
+/// Map the bound element array and find the smallest and largest index within the `elements` range.
+/// Returns `None` if there are no indices in the range, or if every index is the primitive restart index.
+///
+/// If [`Capability::PrimitiveRestartFixedIndex`](crate::state::Capability::PrimitiveRestartFixedIndex)
+/// is enabled, the restart index is skipped.
+///
+/// # Safety
+/// * A buffer must be bound to `ElementArray`, and it must not be mapped.
+/// * The range must not extend beyond the end of the buffer.
+unsafe fn scan_index_range(
+    element_type: &ElementType,
+    elements: core::ops::Range<usize>,
+) -> Option<core::ops::RangeInclusive<usize>> {
+    if elements.start == elements.end {
+        return None;
+    }
+    let size = element_type.size_of();
+    let len = (elements.end - elements.start).checked_mul(size).unwrap();
+
+    let restart = unsafe { gl::IsEnabled(gl::PRIMITIVE_RESTART_FIXED_INDEX) } == gl::TRUE;
+    let restart_index = match element_type {
+        ElementType::U8 => u8::MAX.into(),
+        ElementType::U16 => u16::MAX.into(),
+        ElementType::U32 => u32::MAX.try_into().unwrap_or(usize::MAX),
+    };
+
+    let ptr = unsafe {
+        gl::MapBufferRange(
+            gl::ELEMENT_ARRAY_BUFFER,
+            elements
+                .start
+                .checked_mul(size)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            len.try_into().unwrap(),
+            gl::MAP_READ_BIT,
+        )
+    }
+    .cast::<u8>()
+    .cast_const();
+    assert!(!ptr.is_null());
+
+    // Mapped pointers have no alignment guarantees, read byte-wise.
+    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+    let range = bytes
+        .chunks_exact(size)
+        .map(|index| match element_type {
+            ElementType::U8 => usize::from(index[0]),
+            ElementType::U16 => usize::from(u16::from_ne_bytes([index[0], index[1]])),
+            ElementType::U32 => u32::from_ne_bytes([index[0], index[1], index[2], index[3]])
+                .try_into()
+                .unwrap_or(usize::MAX),
+        })
+        .filter(|&index| !(restart && index == restart_index))
+        .fold(None, |range: Option<(usize, usize)>, index| match range {
+            None => Some((index, index)),
+            Some((min, max)) => Some((min.min(index), max.max(index))),
+        });
+
+    unsafe {
+        gl::UnmapBuffer(gl::ELEMENT_ARRAY_BUFFER);
+    }
+
+    range.map(|(min, max)| min..=max)
+}
 
 /// Specifies the datatype of indices to fetch from the `ElementArray`.
 #[repr(u32)]
@@ -50,6 +167,41 @@ impl ElementType {
             Self::U32 => core::mem::size_of::<u32>(),
         }
     }
+    /// The byte offset of the `index`th element from the start of an element buffer.
+    ///
+    /// ```
+    /// # use glhf::draw::ElementType;
+    /// assert_eq!(ElementType::U16.byte_offset(3), 6);
+    /// assert_eq!(ElementType::U32.index_at_byte(ElementType::U32.byte_offset(5)), 5);
+    /// ```
+    ///
+    /// # Panics
+    /// On overflow.
+    #[must_use]
+    pub fn byte_offset(&self, index: usize) -> usize {
+        index
+            .checked_mul(self.size_of())
+            .expect("element byte offset overflows")
+    }
+    /// The index of the element starting `offset` bytes into an element buffer. The inverse of
+    /// [`Self::byte_offset`].
+    ///
+    /// # Panics
+    /// If `offset` is not a multiple of [`Self::size_of`], as the GL requires elements to be aligned.
+    ///
+    /// ```should_panic
+    /// # use glhf::draw::ElementType;
+    /// // Halfway through the first element.
+    /// let _ = ElementType::U16.index_at_byte(1);
+    /// ```
+    #[must_use]
+    // `usize::is_multiple_of` is too recent to rely on.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn index_at_byte(&self, offset: usize) -> usize {
+        let size = self.size_of();
+        assert!(offset % size == 0, "misaligned element offset {offset}");
+        offset / size
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -74,6 +226,36 @@ pub struct ElementState<'a, Default: marker::Defaultness> {
     pub program: &'a ActiveProgram,
 }
 
+impl<Default: marker::Defaultness> ElementState<'_, Default> {
+    /// Find the smallest and largest index within the `elements` range of the
+    /// [element buffer](Self::elements), suitable for the `index_range` of [`Draw::ranged_elements`].
+    /// Returns `None` if there are no indices in the range, or if every index is the primitive
+    /// restart index.
+    ///
+    /// This is an O(n) pass on the host. The buffer is mapped for reading and unmapped before
+    /// returning, which stalls until all pending GL work writing to it completes - compute the
+    /// range once for static meshes, rather than every draw.
+    ///
+    /// # Panics
+    /// If the range extends beyond the end of the element buffer.
+    #[doc(alias = "glMapBufferRange")]
+    #[must_use]
+    pub fn compute_index_range(
+        &self,
+        element_type: &ElementType,
+        elements: core::ops::Range<usize>,
+    ) -> Option<core::ops::RangeInclusive<usize>> {
+        let end = elements.end.checked_mul(element_type.size_of()).unwrap();
+        assert!(
+            elements.start <= elements.end && end <= self.elements.len(),
+            "compute_index_range() element range out of bounds"
+        );
+        // Safety: `self.elements` proves a buffer is bound, and as it is borrowed, the buffer
+        // cannot be mapped. Just checked the bounds.
+        unsafe { scan_index_range(element_type, elements) }
+    }
+}
+
 /// Bindings to `glDraw*`
 pub struct Draw(pub(crate) NotSync);
 
@@ -81,6 +263,9 @@ impl Draw {
     /// Draw consecutive vertices from the [vertex array](ArrayState::vertex_array),
     /// using its enabled buffers and attributes.
     ///
+    /// # Panics
+    /// In debug builds, if the vertices do not form whole primitives of `mode`, see [`Topology`].
+    ///
     /// # Safety
     /// * For each enabled vertex attribute, vertex fetching must not extend out-of-bounds
     ///   for their given buffers.
@@ -102,6 +287,8 @@ impl Draw {
             .end
             .checked_sub(vertices.start)
             .expect("draw range end before start");
+        #[cfg(debug_assertions)]
+        mode.assert_vertex_count(count);
 
         if instances == 1 {
             // AFAIK, treating instances == 1 as a regular draw is not observably different
@@ -124,9 +311,82 @@ impl Draw {
             }
         }
     }
+    /// Draw consecutive vertices, as [`Self::arrays`], with a single instance.
+    ///
+    /// # Panics
+    /// See [`Self::arrays`].
+    ///
+    /// # Safety
+    /// See [`Self::arrays`].
+    #[doc(alias = "glDrawArrays")]
+    pub unsafe fn arrays_once<Default: marker::Defaultness>(
+        &self,
+        mode: Topology,
+        vertices: core::ops::Range<usize>,
+        state: ArrayState<Default>,
+    ) {
+        unsafe { self.arrays(mode, vertices, 1, state) }
+    }
+    /// Draw consecutive vertices, as [`Self::arrays`], for the consecutive `instances`.
+    ///
+    /// Unlike [`Self::arrays`], the first instance need not be zero - attributes with a non-zero divisor
+    /// are fetched starting at `instances.start`. This allows drawing a sub-range of an instance buffer
+    /// without re-specifying attributes. `gl_InstanceID` still counts from zero.
+    ///
+    /// Base instances are not supported by core GLES. Where the extension is missing, the same
+    /// effect can be had by re-specifying the instanced attributes with their offsets advanced by
+    /// `instances.start` elements.
+    ///
+    /// # Panics
+    /// * If the extension entry point was not loaded.
+    /// * In debug builds, if the vertices do not form whole primitives of `mode`, see [`Topology`].
+    ///
+    /// # Safety
+    /// * [`Extension::BaseInstance`](crate::info::Extension::BaseInstance) must be
+    ///   [supported](crate::GLHF::supports).
+    /// * For each enabled vertex attribute, vertex fetching must not extend out-of-bounds
+    ///   for their given buffers.
+    #[doc(alias = "glDrawArraysInstancedBaseInstanceEXT")]
+    #[doc(alias = "GL_EXT_base_instance")]
+    pub unsafe fn arrays_instanced_base_instance<Default: marker::Defaultness>(
+        &self,
+        mode: Topology,
+        vertices: core::ops::Range<usize>,
+        instances: core::ops::Range<usize>,
+        _state: ArrayState<Default>,
+    ) {
+        if vertices.start == vertices.end || instances.start == instances.end {
+            // Nothing to draw.
+            return;
+        }
+
+        let count = vertices
+            .end
+            .checked_sub(vertices.start)
+            .expect("draw range end before start");
+        let instance_count = instances
+            .end
+            .checked_sub(instances.start)
+            .expect("instance range end before start");
+        #[cfg(debug_assertions)]
+        mode.assert_vertex_count(count);
+
+        unsafe {
+            gl::DrawArraysInstancedBaseInstanceEXT(
+                mode.as_gl(),
+                vertices.start.try_into().unwrap(),
+                count.try_into().unwrap(),
+                instance_count.try_into().unwrap(),
+                instances.start.try_into().unwrap(),
+            );
+        }
+    }
     /// Fetches the indices to draw from the bound [element buffer](ElementState::elements),
     /// and uses those to fetch to vertices from the [vertex array](ElementState::vertex_array).
     ///
+    /// Unlike [`Self::arrays`], the number of indices is not checked against `mode`, as primitive
+    /// restart may split them into several primitives.
+    ///
     /// # Safety
     /// * The index range must not read beyond the end of the element array.
     /// * For each enabled vertex attribute, vertex fetching by index must not extend out-of-bounds
@@ -189,6 +449,139 @@ impl Draw {
             }
         }
     }
+    /// Draw indices from the bound element buffer, as [`Self::elements`], with a single instance.
+    ///
+    /// # Safety
+    /// See [`Self::elements`].
+    #[doc(alias = "glDrawElements")]
+    pub unsafe fn elements_once<Default: marker::Defaultness>(
+        &self,
+        mode: Topology,
+        element_type: ElementType,
+        elements: core::ops::Range<usize>,
+        state: ElementState<Default>,
+    ) {
+        unsafe { self.elements(mode, element_type, elements, 1, state) }
+    }
+    /// Draw `indices` from host memory, as [`Self::elements`], for small and ever-changing index
+    /// sets such as debug geometry or UI.
+    ///
+    /// The indices are uploaded to `scratch`, a buffer kept by the caller for this purpose -
+    /// client-side index pointers are never handed to the GL. Its storage is reallocated with
+    /// `glBufferData` every call, letting the GL orphan the previous contents rather than stall on
+    /// draws still reading them. Still, prefer [`Self::elements`] for large or repeated draws.
+    ///
+    /// The element buffer of the bound vertex array is restored afterwards, `elements` only
+    /// proves that it may be rebound in the meantime.
+    ///
+    /// # Safety
+    /// For each enabled vertex attribute, vertex fetching by index must not extend out-of-bounds
+    /// for their given buffers.
+    #[doc(alias = "glDrawElements")]
+    #[doc(alias = "glBufferData")]
+    pub unsafe fn elements_inline<Default: marker::Defaultness>(
+        &self,
+        mode: Topology,
+        indices: &[u16],
+        scratch: &crate::buffer::Buffer,
+        elements: &mut slot::buffer::Slot<slot::buffer::ElementArray>,
+        _state: ArrayState<Default>,
+    ) {
+        if indices.is_empty() {
+            // Nothing to draw.
+            return;
+        }
+
+        let previous = unsafe {
+            let mut previous = core::mem::MaybeUninit::uninit();
+            gl::GetIntegerv(gl::ELEMENT_ARRAY_BUFFER_BINDING, previous.as_mut_ptr());
+            previous.assume_init()
+        };
+        elements.bind(scratch).data(
+            bytemuck::cast_slice(indices),
+            crate::buffer::usage::Frequency::Stream,
+            crate::buffer::usage::Access::Draw,
+        );
+
+        unsafe {
+            gl::DrawElements(
+                mode.as_gl(),
+                indices.len().try_into().unwrap(),
+                gl::UNSIGNED_SHORT,
+                // Offset zero into `scratch`, which is bound.
+                core::ptr::null(),
+            );
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, previous.try_into().unwrap());
+        }
+    }
+    /// Issue one [`Self::elements`] draw for each range of `draws`, all sharing `state`.
+    ///
+    /// Each draw adds its base vertex to every index fetched, before fetching vertices. This allows
+    /// many meshes to share one vertex and element buffer, with indices relative to their own first
+    /// vertex. Empty ranges are skipped.
+    ///
+    /// Non-zero base vertices require GLES 3.2.
+    ///
+    /// # Panics
+    /// If a base vertex is non-zero and the GLES 3.2 entry point was not loaded.
+    ///
+    /// # Safety
+    /// * No index range may read beyond the end of the element array.
+    /// * For each enabled vertex attribute, vertex fetching by index plus base vertex must not
+    ///   extend out-of-bounds for their given buffers.
+    #[doc(alias = "glDrawElements")]
+    #[doc(alias = "glDrawElementsBaseVertex")]
+    pub unsafe fn multi_elements<Default: marker::Defaultness>(
+        &self,
+        mode: Topology,
+        element_type: ElementType,
+        draws: &[(core::ops::Range<usize>, i32)],
+        state: ElementState<Default>,
+    ) {
+        // Only fetched once for the whole batch.
+        #[cfg(debug_assertions)]
+        let len = state.elements.len();
+        #[cfg(not(debug_assertions))]
+        let _ = state;
+
+        for (elements, base_vertex) in draws {
+            if elements.start == elements.end {
+                continue;
+            }
+            let count = elements
+                .end
+                .checked_sub(elements.start)
+                .expect("draw range end before start");
+            let byte_offset = element_type.byte_offset(elements.start);
+
+            #[cfg(debug_assertions)]
+            assert!(
+                (byte_offset + element_type.byte_offset(count)) <= len,
+                "unsafe precondition violated: draw.multi_elements() element range out of bounds"
+            );
+
+            if *base_vertex == 0 {
+                unsafe {
+                    gl::DrawElements(
+                        mode.as_gl(),
+                        count.try_into().unwrap(),
+                        element_type.as_gl(),
+                        byte_offset as _,
+                    );
+                }
+            } else {
+                unsafe {
+                    gl::DrawElementsBaseVertex(
+                        mode.as_gl(),
+                        count.try_into().unwrap(),
+                        element_type.as_gl(),
+                        byte_offset as _,
+                        *base_vertex,
+                    );
+                }
+            }
+        }
+    }
     /// Fetches the indices to draw from the bound [element buffer](ElementState::elements),
     /// and uses those to fetch to vertices from the [vertex array](ElementState::vertex_array),
     /// additionally assuming that the indices fetched lie within `index_range`.
@@ -197,6 +590,8 @@ impl Draw {
     /// ahead-of-time computation. For maximum performance, the range should be as small as possible with
     /// minimal unused indices.
     ///
+    /// Every [`Topology`] may be used. There is no instanced form - for that, use [`Self::elements`].
+    ///
     /// # Safety
     /// * The index range must not read beyond the end of the element array.
     /// * All index values in the range given by `elements` within the element buffer must be within `index_range`.
@@ -233,6 +628,16 @@ impl Draw {
             );
         }
 
+        #[cfg(all(debug_assertions, feature = "debug-validate-indices"))]
+        {
+            if let Some(actual) = unsafe { scan_index_range(&element_type, elements.clone()) } {
+                assert!(
+                    index_range.contains(actual.start()) && index_range.contains(actual.end()),
+                    "unsafe precondition violated: draw.ranged_elements() fetched indices {actual:?} outside of index_range {index_range:?}"
+                );
+            }
+        }
+
         // (why is there no Instanced form?)
         unsafe {
             gl::DrawRangeElements(
@@ -1,5 +1,5 @@
 //! Types and parameter enums for Framebuffers.
-use super::{gl, NonZeroName};
+use super::{gl, renderbuffer::Renderbuffer, texture::Texture2D, NonZeroName};
 
 /// Buffers available for reading and writing on user-created framebuffers.
 #[derive(PartialEq, Eq)]
@@ -17,8 +17,23 @@ pub enum Buffer {
 
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for Buffer {}
+impl Buffer {
+    /// The attachment point this buffer selects, if any. The inverse of
+    /// [`Attachment::as_draw_buffer`].
+    #[must_use]
+    pub fn attachment(&self) -> Option<Attachment> {
+        match self {
+            Self::None => None,
+            Self::ColorAttachment0 => Some(Attachment::Color0),
+            Self::ColorAttachment1 => Some(Attachment::Color1),
+            Self::ColorAttachment2 => Some(Attachment::Color2),
+            Self::ColorAttachment3 => Some(Attachment::Color3),
+        }
+    }
+}
 
 /// An attachment point for binding a Texture or Renderbuffer to a framebuffer.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[repr(u32)]
 pub enum Attachment {
     Color0 = gl::COLOR_ATTACHMENT0,
@@ -31,6 +46,76 @@ pub enum Attachment {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for Attachment {}
+impl Attachment {
+    /// The buffer selecting this attachment point for reading and drawing, if it is a color
+    /// attachment. The inverse of [`Buffer::attachment`].
+    ///
+    /// ```
+    /// use glhf::framebuffer::{Attachment, Buffer};
+    /// assert!(Attachment::Color2.as_draw_buffer() == Some(Buffer::ColorAttachment2));
+    /// assert!(Attachment::Depth.as_draw_buffer().is_none());
+    /// ```
+    #[must_use]
+    pub fn as_draw_buffer(&self) -> Option<Buffer> {
+        match self {
+            Self::Color0 => Some(Buffer::ColorAttachment0),
+            Self::Color1 => Some(Buffer::ColorAttachment1),
+            Self::Color2 => Some(Buffer::ColorAttachment2),
+            Self::Color3 => Some(Buffer::ColorAttachment3),
+            Self::Depth | Self::Stencil | Self::DepthStencil => None,
+        }
+    }
+}
+
+/// The kind of image bound to an [`Attachment`] point.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum AttachmentKind {
+    /// Nothing is attached.
+    None = gl::NONE,
+    Texture = gl::TEXTURE,
+    Renderbuffer = gl::RENDERBUFFER,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for AttachmentKind {}
+
+/// How the components of an attached image are interpreted.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ComponentType {
+    /// Floating point, e.g. [`InternalFormat::Rgba16f`](crate::texture::InternalFormat::Rgba16f).
+    Float = gl::FLOAT,
+    /// Signed integer, e.g. [`InternalFormat::R32i`](crate::texture::InternalFormat::R32i).
+    Int = gl::INT,
+    /// Unsigned integer, e.g. [`InternalFormat::R32ui`](crate::texture::InternalFormat::R32ui).
+    UnsignedInt = gl::UNSIGNED_INT,
+    /// Unsigned fixed-point in `[0, 1]`, e.g. [`InternalFormat::Rgba8`](crate::texture::InternalFormat::Rgba8).
+    UnsignedNormalized = gl::UNSIGNED_NORMALIZED,
+    /// Signed fixed-point in `[-1, 1]`, e.g. [`InternalFormat::Rgba8Snorm`](crate::texture::InternalFormat::Rgba8Snorm).
+    SignedNormalized = gl::SIGNED_NORMALIZED,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for ComponentType {}
+
+/// An image which may be attached to a framebuffer.
+#[derive(Copy, Clone)]
+pub enum AttachmentSource<'a> {
+    /// A single mip level of a 2D texture.
+    Texture2D { texture: &'a Texture2D, level: u32 },
+    /// The entirety of a renderbuffer.
+    Renderbuffer(&'a Renderbuffer),
+}
+/// Attach the base mip level.
+impl<'a> From<&'a Texture2D> for AttachmentSource<'a> {
+    fn from(texture: &'a Texture2D) -> Self {
+        Self::Texture2D { texture, level: 0 }
+    }
+}
+impl<'a> From<&'a Renderbuffer> for AttachmentSource<'a> {
+    fn from(renderbuffer: &'a Renderbuffer) -> Self {
+        Self::Renderbuffer(renderbuffer)
+    }
+}
 
 /// Buffers available for reading and writing on the Default framebuffer.
 #[derive(PartialEq, Eq)]
@@ -53,7 +138,9 @@ pub struct Incomplete(pub(crate) NonZeroName);
 impl crate::sealed::Sealed for Incomplete {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for Incomplete {}
+unsafe impl crate::ThinGLObject for Incomplete {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Framebuffer;
+}
 
 /// A framebuffer that is known to be complete.
 #[repr(transparent)]
@@ -63,7 +150,9 @@ pub struct Complete(pub(crate) NonZeroName);
 impl crate::sealed::Sealed for Complete {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for Complete {}
+unsafe impl crate::ThinGLObject for Complete {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Framebuffer;
+}
 
 impl Incomplete {
     /// Make `self` into a completed framebuffer, without checking with the GL.
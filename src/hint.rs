@@ -17,22 +17,79 @@ pub enum Mode {
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for Mode {}
 
-/// Hints to the implementation.
+/// Set by [`Hint::release_compiler`] to catch later compiles in debug builds.
+///
+/// This is global, not per-context, as the GL provides no way to ask. Being global, it also
+/// survives recreating the `GLHF` with [`GLHF::current`](crate::GLHF::current) every frame.
+#[cfg(debug_assertions)]
+pub(crate) static COMPILER_RELEASED: core::sync::atomic::AtomicBool =
+    core::sync::atomic::AtomicBool::new(false);
 
+/// Hints to the implementation.
 pub struct Hint(pub(crate) NotSync);
 impl Hint {
     /// Signal that you won't be compiling more shaders or programs.
     ///
     /// It is still valid to issue compilation and linking calls after this,
-    /// but there may be a significant performance penalty.
+    /// but there may be a significant performance penalty, and some drivers fail
+    /// to compile anything at all. As such, in debug builds
+    /// [`slot::program::Slot::compile`](crate::slot::program::Slot::compile) panics if
+    /// called after this, until [`Self::reacquire_compiler`]. See [`Self::with_compiler`] for
+    /// compiling on demand after releasing.
+    ///
+    /// The debug check is shared by every context, as the GL provides no way to tell them apart.
+    /// Releasing the compiler of one context thus also flags compiles on any other, which may be
+    /// silenced with [`Self::reacquire_compiler`].
     #[doc(alias = "glReleaseShaderCompiler")]
     pub fn release_compiler(&self) -> &Self {
         unsafe {
             gl::ReleaseShaderCompiler();
         }
+        #[cfg(debug_assertions)]
+        COMPILER_RELEASED.store(true, core::sync::atomic::Ordering::Relaxed);
+        self
+    }
+    /// Acknowledge that shaders will be compiled after [`Self::release_compiler`], silencing
+    /// the debug check.
+    ///
+    /// The GL has no matching call - the compiler is reloaded implicitly by the next compile,
+    /// with all the costs and driver bugs that entails. This is a no-op in release builds.
+    pub fn reacquire_compiler(&self) -> &Self {
+        #[cfg(debug_assertions)]
+        COMPILER_RELEASED.store(false, core::sync::atomic::Ordering::Relaxed);
         self
     }
-    /// The quality of images generated by calls to `glGenerateMipmap`.
+    /// Compile shaders within `f`, then [release](Self::release_compiler) the compiler again.
+    ///
+    /// This suits lazily compiling shaders on demand while freeing the compiler's memory between
+    /// bursts. As with [`Self::reacquire_compiler`], the compiler is reloaded implicitly by the
+    /// first compile within `f`.
+    ///
+    /// Usage:
+    /// ```no_run
+    /// # use glhf::program::Vertex;
+    /// # let gl : glhf::GLHF = todo!();
+    /// let shader = gl.new.shader::<Vertex>();
+    /// let compiled = gl
+    ///     .hint
+    ///     .with_compiler(&gl.program, |program| program.compile(shader, "..."));
+    /// ```
+    #[doc(alias = "glReleaseShaderCompiler")]
+    pub fn with_compiler<R>(
+        &self,
+        program: &crate::slot::program::Slot,
+        f: impl FnOnce(&crate::slot::program::Slot) -> R,
+    ) -> R {
+        self.reacquire_compiler();
+        let result = f(program);
+        self.release_compiler();
+        result
+    }
+    /// The quality of images generated by calls to
+    /// [`generate_mipmap`](crate::slot::texture::Active::generate_mipmap).
+    ///
+    /// See also [`generate_mipmap_with_quality`](crate::slot::texture::Active::generate_mipmap_with_quality),
+    /// which scopes the hint to a single call.
     #[doc(alias = "glHint")]
     #[doc(alias = "GENERATE_MIPMAP_HINT")]
     pub fn generate_mipmap(&self, mode: Mode) -> &Self {
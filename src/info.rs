@@ -0,0 +1,227 @@
+//! Types describing the capabilities of the implementation.
+use crate::gl;
+use core::ffi::CStr;
+
+/// GL extensions which this crate exposes functionality for.
+///
+/// Functions depending on an extension document it, and must not be called
+/// unless [`GLHF::supports`](crate::GLHF::supports) returns `true` for it.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Extension {
+    /// `GL_EXT_base_instance`, offsetting the instance used to fetch instanced attributes.
+    ///
+    /// See [`Draw::arrays_instanced_base_instance`](crate::draw::Draw::arrays_instanced_base_instance).
+    BaseInstance,
+    /// `GL_OES_viewport_array`, multiple viewports and scissor rectangles selected by
+    /// `gl_ViewportIndex` in a geometry shader.
+    ///
+    /// See [`State::viewport_indexed`](crate::state::State::viewport_indexed).
+    ViewportArray,
+    /// `GL_OES_geometry_shader`, programmable processing of whole primitives. This is core
+    /// in GLES 3.2, where the extension need not be advertised.
+    ///
+    /// See [`program::Geometry`](crate::program::Geometry).
+    GeometryShader,
+    /// `GL_OES_tessellation_shader`, subdivision of patches on the GPU. This is core
+    /// in GLES 3.2, where the extension need not be advertised.
+    ///
+    /// See [`program::TessControl`](crate::program::TessControl).
+    TessellationShader,
+    /// `GL_KHR_robustness`, detection of GPU resets and bounds-checked buffer access. This is core
+    /// in GLES 3.2, where the extension need not be advertised.
+    ///
+    /// See [`GLHF::reset_status`](crate::GLHF::reset_status).
+    Robustness,
+    /// `GL_EXT_disjoint_timer_query`, measuring the GPU time taken by a span of commands.
+    ///
+    /// See [`GLHF::timer_query`](crate::GLHF::timer_query).
+    DisjointTimerQuery,
+    /// `GL_EXT_polygon_offset_clamp`, limiting the depth offset applied to sloped polygons.
+    ///
+    /// See [`State::polygon_offset_clamp`](crate::state::State::polygon_offset_clamp).
+    PolygonOffsetClamp,
+    /// `GL_EXT_texture_norm16`, 16-bit normalized color formats such as
+    /// [`InternalFormat::Rg16`](crate::texture::InternalFormat::Rg16).
+    TextureNorm16,
+    /// `GL_EXT_texture_sRGB_R8`, the single-channel sRGB format
+    /// [`InternalFormat::Sr8`](crate::texture::InternalFormat::Sr8).
+    TextureSrgbR8,
+    /// `GL_EXT_texture_sRGB_RG8`, the two-channel sRGB format
+    /// [`InternalFormat::Srg8`](crate::texture::InternalFormat::Srg8).
+    TextureSrgbRg8,
+}
+impl Extension {
+    /// Every extension, in declaration order.
+    pub const ALL: &'static [Self] = &[
+        Self::BaseInstance,
+        Self::ViewportArray,
+        Self::GeometryShader,
+        Self::TessellationShader,
+        Self::Robustness,
+        Self::DisjointTimerQuery,
+        Self::PolygonOffsetClamp,
+        Self::TextureNorm16,
+        Self::TextureSrgbR8,
+        Self::TextureSrgbRg8,
+    ];
+    /// The name of the extension, as reported by `glGetStringi(GL_EXTENSIONS, ..)`.
+    #[must_use]
+    pub fn name(&self) -> &'static CStr {
+        match self {
+            Self::BaseInstance => c"GL_EXT_base_instance",
+            Self::ViewportArray => c"GL_OES_viewport_array",
+            Self::GeometryShader => c"GL_OES_geometry_shader",
+            Self::TessellationShader => c"GL_OES_tessellation_shader",
+            Self::Robustness => c"GL_KHR_robustness",
+            Self::DisjointTimerQuery => c"GL_EXT_disjoint_timer_query",
+            Self::PolygonOffsetClamp => c"GL_EXT_polygon_offset_clamp",
+            Self::TextureNorm16 => c"GL_EXT_texture_norm16",
+            Self::TextureSrgbR8 => c"GL_EXT_texture_sRGB_R8",
+            Self::TextureSrgbRg8 => c"GL_EXT_texture_sRGB_RG8",
+        }
+    }
+    /// The bit representing this extension in [`EXTENSION_CACHE`].
+    fn cache_bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Bit set in [`EXTENSION_CACHE`] once it has been populated.
+const CACHE_POPULATED: u32 = 1 << 31;
+/// Lazily populated set of supported extensions, one bit per [`Extension`] by discriminant.
+///
+/// The GL gives no way to identify the current context, so this is shared by all of them.
+/// `GLHF` being a ZST, this is the only place a cache can live without `std`.
+static EXTENSION_CACHE: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
+
+/// Check the cache for `extension`, populating it first if needed.
+pub(crate) fn cached_supports(extension: Extension) -> bool {
+    use core::sync::atomic::Ordering;
+
+    let mut cache = EXTENSION_CACHE.load(Ordering::Relaxed);
+    if cache & CACHE_POPULATED == 0 {
+        cache = Extension::ALL
+            .iter()
+            .filter(|extension| unsafe { query_supports(**extension) })
+            .fold(CACHE_POPULATED, |cache, extension| {
+                cache | extension.cache_bit()
+            });
+        // Racing contexts would compute the same value anyway, nothing to synchronize.
+        EXTENSION_CACHE.store(cache, Ordering::Relaxed);
+    }
+    cache & extension.cache_bit() != 0
+}
+/// Forget all cached extension support, to be re-queried on next use.
+pub(crate) fn clear_cache() {
+    EXTENSION_CACHE.store(0, core::sync::atomic::Ordering::Relaxed);
+}
+/// Ask the GL whether `extension` is supported. This is a linear search of every extension.
+///
+/// # Safety
+/// A context must be current.
+unsafe fn query_supports(extension: Extension) -> bool {
+    let name = extension.name();
+    let count = unsafe {
+        let mut count = core::mem::MaybeUninit::uninit();
+        gl::GetIntegerv(gl::NUM_EXTENSIONS, count.as_mut_ptr());
+        count.assume_init()
+    };
+    (0..gl::types::GLuint::try_from(count).unwrap()).any(|index| {
+        let ptr = unsafe { gl::GetStringi(gl::EXTENSIONS, index) };
+        // Safety: GL returns either null or a nul-terminated string that lives as long as the context.
+        !ptr.is_null() && unsafe { CStr::from_ptr(ptr.cast()) } == name
+    })
+}
+
+/// Targets for which [`GLHF::format_support`](crate::GLHF::format_support) may be queried.
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FormatTarget {
+    Renderbuffer = gl::RENDERBUFFER,
+    /// Multisampled 2D textures. Requires GLES 3.1.
+    Texture2DMultisample = gl::TEXTURE_2D_MULTISAMPLE,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for FormatTarget {}
+
+/// How an internal format may be used with a [`FormatTarget`].
+///
+/// GLES can only be asked about formats that are renderable, so every format that can be queried
+/// may be attached to a framebuffer without multisampling. This describes the multisampling
+/// support on top of that.
+#[derive(Copy, Clone, Debug)]
+pub struct FormatSupport {
+    pub(crate) sample_counts: [u32; Self::MAX_SAMPLE_COUNTS],
+    pub(crate) len: usize,
+}
+impl FormatSupport {
+    /// More than this many distinct sample counts are truncated, keeping the largest.
+    /// Real implementations report far fewer.
+    pub const MAX_SAMPLE_COUNTS: usize = 16;
+    /// The sample counts supported for multisampled storage, in descending order.
+    /// Empty if the format may not be multisampled, as is the case for integer formats.
+    #[must_use]
+    pub fn sample_counts(&self) -> &[u32] {
+        &self.sample_counts[..self.len]
+    }
+    /// The largest supported sample count, or zero if the format may not be multisampled.
+    #[must_use]
+    pub fn max_samples(&self) -> u32 {
+        self.sample_counts().first().copied().unwrap_or(0)
+    }
+}
+
+/// Shader stages whose numeric precision may be queried with
+/// [`GLHF::shader_precision`](crate::GLHF::shader_precision).
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ShaderType {
+    Vertex = gl::VERTEX_SHADER,
+    Fragment = gl::FRAGMENT_SHADER,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for ShaderType {}
+
+/// GLSL precision qualifiers, paired with the numeric type they qualify.
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PrecisionType {
+    LowFloat = gl::LOW_FLOAT,
+    MediumFloat = gl::MEDIUM_FLOAT,
+    HighFloat = gl::HIGH_FLOAT,
+    LowInt = gl::LOW_INT,
+    MediumInt = gl::MEDIUM_INT,
+    HighInt = gl::HIGH_INT,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for PrecisionType {}
+
+/// The representable range and precision of a [`PrecisionType`] in a shader stage, as reported by
+/// [`GLHF::shader_precision`](crate::GLHF::shader_precision).
+///
+/// Values are log2 of the true quantity - a `highp float` implemented as IEEE 754 single precision
+/// reports `range: [127, 127]` and `precision: 23`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct PrecisionFormat {
+    /// The log2 of the absolute values of the minimum and maximum representable values.
+    pub range: [i32; 2],
+    /// The log2 of the precision, i.e. the number of mantissa bits. Always zero for integer types.
+    pub precision: i32,
+}
+
+/// Whether the context has been lost to a GPU reset, as reported by
+/// [`GLHF::reset_status`](crate::GLHF::reset_status).
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ResetStatus {
+    /// No reset has occurred since the last query.
+    NoError = gl::NO_ERROR,
+    /// A reset was caused by this context.
+    GuiltyContextReset = gl::GUILTY_CONTEXT_RESET,
+    /// A reset was caused by another context.
+    InnocentContextReset = gl::INNOCENT_CONTEXT_RESET,
+    /// A reset occurred, but the cause is unknown.
+    UnknownContextReset = gl::UNKNOWN_CONTEXT_RESET,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for ResetStatus {}
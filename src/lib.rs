@@ -32,11 +32,23 @@
 //! * **`alloc` (default)**
 //! > Enables functions that involve `glGet`ting `CStrings`, such as program linker logs.
 //! > without this feature, the user must manually invoke the relavent GL calls.
+//! > Also enables [`arena`], for scoped deletion of transient objects.
 //! * **`mint`**
 //! > Enables easy use of linear algebra crates with program uniforms by implementing
 //! > `From<mint::ColumnMatrix*<f32>> for Matrix`. Note that `mint` uses the transpose of
 //! > the matrix size notation used by GL - a GLSL `mat4x3` is represented in mint by
 //! > `ColumnMatrix3x4`.
+//! * **`debug-validate-indices`**
+//! > In debug builds, [`draw::Draw::ranged_elements`] maps the element buffer and checks that
+//! > every fetched index lies within the given `index_range`. This is very expensive, stalling
+//! > on all pending GL work that writes to the element buffer.
+//! * **`debug-object-tracking`**
+//! > In debug builds, records every object name generated and deleted through this crate, and
+//! > panics when binding a name that has since been deleted. This catches stale handles, which
+//! > the GL may have silently recycled for a different object. Names from outside this crate
+//! > are not checked. Textures bound to a different
+//! > target than they were initialized with, e.g. a 2D texture as a cube map, also panic.
+//! > Requires `alloc`, and takes a global lock on every generation, deletion, and bind.
 //!
 //! This crate is `no_std` by default.
 
@@ -60,18 +72,25 @@ pub mod gl {
 // functions that accept `Active` slots, re-export them in a slightly more accessible place.
 pub use slot::marker;
 
+#[cfg(feature = "alloc")]
+pub mod arena;
 pub mod buffer;
 pub mod draw;
 pub mod framebuffer;
 pub mod hint;
+pub mod info;
 pub mod new;
 pub mod program;
+pub mod query;
 pub mod renderbuffer;
 pub mod slot;
 pub mod state;
 pub mod texture;
 pub mod vertex_array;
 
+#[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+mod tracking;
+
 /// Entry point for GL calls.
 // That's not what we're doing, clippy!
 #[allow(clippy::manual_non_exhaustive)]
@@ -82,6 +101,8 @@ pub struct GLHF {
     pub framebuffer: slot::framebuffer::Slots,
     /// `glBindBuffer`
     pub buffer: slot::buffer::Slots,
+    /// `glBindRenderbuffer`
+    pub renderbuffer: slot::renderbuffer::Slot,
     /// `glBindVertexArray`
     pub vertex_array: slot::vertex_array::Slot,
     /// `glGen*`
@@ -96,9 +117,14 @@ pub struct GLHF {
     pub state: state::State,
     _cant_destructure: (),
 }
+// `GLHF::current` is documented to be free, which relies on there being nothing to construct.
+const _: () = assert!(core::mem::size_of::<GLHF>() == 0);
 impl GLHF {
     /// Create a wrapper for the currently bound context.
-    /// This is a no-op function, and is free to recreate every frame.
+    /// This is a no-op function in release builds, and is free to recreate every frame.
+    ///
+    /// In debug builds, any errors left pending by earlier GL calls are discarded with
+    /// `glGetError`, such that they are not attributed to calls made through `Self`.
     ///
     /// # Safety
     /// * There must be a current GL context on the calling thread.
@@ -113,7 +139,18 @@ impl GLHF {
     #[must_use]
     pub unsafe fn current() -> Self {
         use core::marker::PhantomData;
-        use slot::{buffer, framebuffer, program, texture, vertex_array};
+        use slot::{buffer, framebuffer, program, renderbuffer, texture, vertex_array};
+
+        #[cfg(debug_assertions)]
+        {
+            // Each call pops one error flag. Bounded, as a lost context may report
+            // `GL_CONTEXT_LOST` indefinitely.
+            for _ in 0..16 {
+                if unsafe { gl::GetError() } == gl::NO_ERROR {
+                    break;
+                }
+            }
+        }
 
         // I find it really funny that all this code is constructing a ZST, and is thus a no-op, Lol
         Self {
@@ -122,6 +159,10 @@ impl GLHF {
                 d3: texture::Slot::<crate::texture::D3>(PhantomData, PhantomData),
                 d2_array: texture::Slot::<crate::texture::D2Array>(PhantomData, PhantomData),
                 cube: texture::Slot::<crate::texture::Cube>(PhantomData, PhantomData),
+                d2_multisample: texture::Slot::<crate::texture::D2Multisample>(
+                    PhantomData,
+                    PhantomData,
+                ),
             },
             framebuffer: framebuffer::Slots {
                 draw: framebuffer::Slot(PhantomData, PhantomData),
@@ -137,6 +178,7 @@ impl GLHF {
                 transform_feedback: buffer::Slot(PhantomData, PhantomData),
                 uniform: buffer::Slot(PhantomData, PhantomData),
             },
+            renderbuffer: renderbuffer::Slot(PhantomData),
             vertex_array: vertex_array::Slot(PhantomData),
             new: new::New(PhantomData),
             program: program::Slot(PhantomData),
@@ -146,16 +188,350 @@ impl GLHF {
             _cant_destructure: (),
         }
     }
+    /// Query whether the current context supports the given extension.
+    ///
+    /// The first call queries the GL for every [`info::Extension`] and caches the result, making
+    /// this cheap to call every frame. The cache is global rather than per-context, and will *not*
+    /// reflect a switch to a context with different extensions - call
+    /// [`Self::refresh_extensions`] after making such a context current.
+    #[doc(alias = "glGetStringi")]
+    #[doc(alias = "GL_EXTENSIONS")]
+    #[doc(alias = "GL_NUM_EXTENSIONS")]
+    #[must_use]
+    pub fn supports(&self, extension: info::Extension) -> bool {
+        info::cached_supports(extension)
+    }
+    /// Discard the extension support cached by [`Self::supports`], to be queried again from the
+    /// current context on next use.
+    pub fn refresh_extensions(&self) -> &Self {
+        info::clear_cache();
+        self
+    }
+    /// Start a scope in which objects may be handed to an [`arena::Arena`] for deletion at the
+    /// end of the scope. The arena dereferences to `self`.
+    ///
+    /// ```no_run
+    /// # fn frame(gl: &mut glhf::GLHF) {
+    /// let mut frame = gl.arena();
+    /// let [staging] = frame.new.buffers();
+    /// // ... use `staging` through `frame` like a `GLHF` ...
+    /// frame.adopt(staging);
+    /// // `staging` is deleted here.
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use = "dropping the arena immediately deletes nothing"]
+    pub fn arena(&mut self) -> arena::Arena<'_> {
+        arena::Arena::new(self)
+    }
+    /// Query how `format` may be used with `target`, namely which sample counts
+    /// are supported for multisampled storage.
+    ///
+    /// Where `target` is not supported, such as [`info::FormatTarget::Texture2DMultisample`] before
+    /// GLES 3.1, no sample counts are reported.
+    ///
+    /// This is not cached and invokes two `glGet`s.
+    #[doc(alias = "glGetInternalformativ")]
+    #[doc(alias = "GL_NUM_SAMPLE_COUNTS")]
+    #[doc(alias = "GL_SAMPLES")]
+    #[must_use]
+    pub fn format_support(
+        &self,
+        target: info::FormatTarget,
+        format: renderbuffer::InternalFormat,
+    ) -> info::FormatSupport {
+        // Left as zero if the GL rejects the query, e.g. multisampled textures before GLES 3.1.
+        let mut count = 0;
+        unsafe {
+            gl::GetInternalformativ(
+                target.as_gl(),
+                format.as_gl(),
+                gl::NUM_SAMPLE_COUNTS,
+                1,
+                &mut count,
+            );
+        }
+        let len = usize::try_from(count)
+            .unwrap()
+            .min(info::FormatSupport::MAX_SAMPLE_COUNTS);
+
+        let mut sample_counts = [0; info::FormatSupport::MAX_SAMPLE_COUNTS];
+        if len != 0 {
+            unsafe {
+                // Writes at most `len` values, truncating the smallest counts.
+                gl::GetInternalformativ(
+                    target.as_gl(),
+                    format.as_gl(),
+                    gl::SAMPLES,
+                    len.try_into().unwrap(),
+                    sample_counts.as_mut_ptr().cast(),
+                );
+            }
+        }
+        info::FormatSupport { sample_counts, len }
+    }
+    /// Query the range and precision of a numeric type in a shader stage.
+    ///
+    /// Implementations may offer far less than IEEE 754 precision, especially in fragment shaders.
+    /// A type the stage does not support reports zero range and precision.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetShaderPrecisionFormat")]
+    #[must_use]
+    pub fn shader_precision(
+        &self,
+        shader_type: info::ShaderType,
+        precision_type: info::PrecisionType,
+    ) -> info::PrecisionFormat {
+        let mut range = [0; 2];
+        let mut precision = 0;
+        unsafe {
+            gl::GetShaderPrecisionFormat(
+                shader_type.as_gl(),
+                precision_type.as_gl(),
+                range.as_mut_ptr(),
+                &mut precision,
+            );
+        }
+        info::PrecisionFormat { range, precision }
+    }
+    /// Query whether the context has been lost to a GPU reset since the last call.
+    ///
+    /// After any reset, the context and every object in it is unusable - the context and all
+    /// resources must be recreated. Resets are only reported for contexts created with a
+    /// `LOSE_CONTEXT_ON_RESET` notification strategy, otherwise this always returns
+    /// [`info::ResetStatus::NoError`].
+    ///
+    /// Requires GLES 3.2 or [`info::Extension::Robustness`].
+    ///
+    /// # Panics
+    /// If the entry point was not loaded.
+    #[doc(alias = "glGetGraphicsResetStatus")]
+    #[doc(alias = "glGetGraphicsResetStatusKHR")]
+    #[must_use]
+    pub fn reset_status(&self) -> info::ResetStatus {
+        match unsafe { gl::GetGraphicsResetStatus() } {
+            gl::GUILTY_CONTEXT_RESET => info::ResetStatus::GuiltyContextReset,
+            gl::INNOCENT_CONTEXT_RESET => info::ResetStatus::InnocentContextReset,
+            gl::UNKNOWN_CONTEXT_RESET => info::ResetStatus::UnknownContextReset,
+            _ => info::ResetStatus::NoError,
+        }
+    }
+    /// Bind the default framebuffer for drawing and clear the given aspects, as is typical at the
+    /// start of a frame.
+    ///
+    /// This is shorthand for `framebuffer.draw.bind_default().clear(mask)`, use that instead if
+    /// the bound framebuffer is needed afterwards, e.g. to draw into it.
+    ///
+    /// # Panics
+    /// See [`slot::framebuffer::Active::clear`].
+    #[doc(alias = "glClear")]
+    pub fn clear_default(&mut self, mask: slot::framebuffer::AspectMask) -> &mut Self {
+        self.framebuffer.draw.bind_default().clear(mask);
+        self
+    }
+    /// Upload `vertices` into a new buffer, and create a vertex array fetching each attribute of
+    /// `layout` from it, with those attributes enabled.
+    ///
+    /// This is shorthand for the most common vertex setup. For anything else, such as multiple
+    /// buffers or instanced attributes, use [`slot::buffer`] and [`slot::vertex_array`] directly.
+    ///
+    /// The buffer is left bound to [`array`](slot::buffer::Slots::array) and the vertex array to
+    /// [`vertex_array`](Self::vertex_array). The buffer's usage is static draw. Both objects
+    /// must be deleted by the user.
+    ///
+    /// Usage:
+    /// ```no_run
+    /// # use glhf::vertex_array::VertexLayout;
+    /// # let mut gl : glhf::GLHF = todo!();
+    /// # let layout : VertexLayout<[f32; 3]> = todo!();
+    /// let (buffer, vertex_array) = gl.upload_mesh(&[[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]], &layout);
+    /// ```
+    ///
+    /// # Panics
+    /// See [`slot::vertex_array::Active::attribute`].
+    #[doc(alias = "glBufferData")]
+    #[doc(alias = "glVertexAttribPointer")]
+    pub fn upload_mesh<V: bytemuck::Pod>(
+        &mut self,
+        vertices: &[V],
+        layout: &vertex_array::VertexLayout<V>,
+    ) -> (buffer::Buffer, vertex_array::VertexArray) {
+        let [buffer] = self.new.buffers();
+        let [array] = self.new.vertex_arrays();
+
+        let source = self.buffer.array.bind(&buffer).data(
+            bytemuck::cast_slice(vertices),
+            buffer::usage::Frequency::Static,
+            buffer::usage::Access::Draw,
+        );
+        let active = self.vertex_array.bind(&array);
+        for &(index, attribute) in layout.attributes() {
+            active.attribute(source, index, attribute, Some(true));
+        }
+
+        (buffer, array)
+    }
+    /// Begin timing the GPU execution of the commands issued through the returned scope, which
+    /// ends the timing when dropped.
+    ///
+    /// The result becomes available some time later, see [`Self::timer_query_result`].
+    ///
+    /// Usage:
+    /// ```no_run
+    /// # let mut gl : glhf::GLHF = todo!();
+    /// let [query] = gl.new.timer_queries();
+    /// unsafe {
+    ///     let mut timed = gl.timer_query(&query);
+    ///     // ... draw through `timed` like a `GLHF` ...
+    /// }
+    /// // Some frames later...
+    /// if let Some(nanos) = unsafe { gl.timer_query_result(&query) } {
+    ///     if !unsafe { gl.was_disjoint() } {
+    ///         // `nanos` is trustworthy.
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    /// * [`Extension::DisjointTimerQuery`](info::Extension::DisjointTimerQuery) must be
+    ///   [supported](Self::supports).
+    /// * No timer query may already be active, such as one begun outside of this crate or an
+    ///   enclosing [`TimerScope`](query::TimerScope) which is still alive.
+    #[doc(alias = "glBeginQuery")]
+    #[doc(alias = "glEndQuery")]
+    #[doc(alias = "GL_TIME_ELAPSED_EXT")]
+    #[doc(alias = "GL_EXT_disjoint_timer_query")]
+    pub unsafe fn timer_query(&mut self, query: &query::TimerQuery) -> query::TimerScope<'_> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        tracking::assert_live(query);
+        #[cfg(debug_assertions)]
+        {
+            let current = unsafe {
+                let mut current = core::mem::MaybeUninit::uninit();
+                gl::GetQueryiv(
+                    gl::TIME_ELAPSED_EXT,
+                    gl::CURRENT_QUERY_EXT,
+                    current.as_mut_ptr(),
+                );
+                current.assume_init()
+            };
+            assert_eq!(current, 0, "a timer query is already active");
+        }
+        unsafe {
+            gl::BeginQuery(gl::TIME_ELAPSED_EXT, query.name().get());
+        }
+        query::TimerScope::new(self)
+    }
+    /// Fetch the time in nanoseconds measured by the last [`Self::timer_query`] on `query`, or
+    /// `None` if the GL has not yet finished executing the timed commands.
+    ///
+    /// This never waits on the GL. Poll it in a later frame rather than spinning on it.
+    ///
+    /// A result may be garbage if the GPU was disjoint while timing, check [`Self::was_disjoint`]
+    /// after fetching results and discard them if it reports `true`.
+    ///
+    /// # Panics
+    /// If the extension entry point was not loaded.
+    ///
+    /// # Safety
+    /// * [`Extension::DisjointTimerQuery`](info::Extension::DisjointTimerQuery) must be
+    ///   [supported](Self::supports).
+    /// * `query` must have been used with [`Self::timer_query`], and not be timing currently.
+    #[doc(alias = "glGetQueryObjectuiv")]
+    #[doc(alias = "glGetQueryObjectui64vEXT")]
+    #[doc(alias = "GL_QUERY_RESULT_AVAILABLE")]
+    #[doc(alias = "GL_QUERY_RESULT")]
+    #[must_use]
+    pub unsafe fn timer_query_result(&self, query: &query::TimerQuery) -> Option<u64> {
+        let name = unsafe { query.name() }.get();
+        let available = unsafe {
+            let mut available = core::mem::MaybeUninit::uninit();
+            gl::GetQueryObjectuiv(name, gl::QUERY_RESULT_AVAILABLE, available.as_mut_ptr());
+            available.assume_init()
+        };
+        (available == gl::TRUE.into()).then(|| unsafe {
+            let mut nanos = core::mem::MaybeUninit::uninit();
+            gl::GetQueryObjectui64vEXT(name, gl::QUERY_RESULT, nanos.as_mut_ptr());
+            nanos.assume_init()
+        })
+    }
+    /// Query whether the GPU was disjoint since the last call, e.g. due to a power-saving clock
+    /// change or a context switch. If so, the results of any timer queries active in that time
+    /// are meaningless and should be discarded.
+    ///
+    /// The flag is cleared by this call - to cover a query, check after its result is available.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    ///
+    /// # Safety
+    /// [`Extension::DisjointTimerQuery`](info::Extension::DisjointTimerQuery) must be
+    /// [supported](Self::supports).
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_GPU_DISJOINT_EXT")]
+    #[must_use]
+    pub unsafe fn was_disjoint(&self) -> bool {
+        unsafe {
+            let mut disjoint = core::mem::MaybeUninit::uninit();
+            gl::GetIntegerv(gl::GPU_DISJOINT_EXT, disjoint.as_mut_ptr());
+            disjoint.assume_init() != 0
+        }
+    }
+    /// Query whether `object` names a live GL object of its type, e.g. via `glIsBuffer` for a
+    /// [`buffer::Buffer`].
+    ///
+    /// This is a debugging aid for names received through interop, catching use-after-delete and
+    /// names of the wrong type. Note that buffers, textures, framebuffers, renderbuffers, and vertex
+    /// arrays only become objects once first bound - a freshly generated name reports `false`.
+    #[doc(alias = "glIsBuffer")]
+    #[doc(alias = "glIsFramebuffer")]
+    #[doc(alias = "glIsProgram")]
+    #[doc(alias = "glIsQuery")]
+    #[doc(alias = "glIsRenderbuffer")]
+    #[doc(alias = "glIsShader")]
+    #[doc(alias = "glIsTexture")]
+    #[doc(alias = "glIsVertexArray")]
+    #[must_use]
+    pub fn is_valid<Object: ThinGLObject>(&self, object: &Object) -> bool {
+        use sealed::ObjectKind;
+        let is_object = match Object::KIND {
+            ObjectKind::Buffer => gl::IsBuffer,
+            ObjectKind::Framebuffer => gl::IsFramebuffer,
+            ObjectKind::Program => gl::IsProgram,
+            ObjectKind::Query => gl::IsQuery,
+            ObjectKind::Renderbuffer => gl::IsRenderbuffer,
+            ObjectKind::Shader => gl::IsShader,
+            ObjectKind::Texture => gl::IsTexture,
+            ObjectKind::VertexArray => gl::IsVertexArray,
+        };
+        unsafe { is_object(object.name().get()) == gl::TRUE }
+    }
 }
 
 mod sealed {
     pub trait Sealed {}
+
+    /// The namespace a [`ThinGLObject`](super::ThinGLObject)'s name belongs to.
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    pub enum ObjectKind {
+        Buffer,
+        Framebuffer,
+        Program,
+        Query,
+        Renderbuffer,
+        Shader,
+        Texture,
+        VertexArray,
+    }
 }
 
 /// # Safety
 /// * A pointer to `self` must be safely readable and writable as `NonZero<GLuint>`.
 /// * A value of `NonZero<GLuint>` is a fully-initialized value of `self`.
 pub unsafe trait ThinGLObject: sealed::Sealed + Sized {
+    /// The namespace of this object's name, e.g. for choosing the right `glIs*` call.
+    #[doc(hidden)]
+    const KIND: sealed::ObjectKind;
     /// Fetch the "name" of the object, the unique ID used to interact with the GL.
     /// # Safety
     /// TODO: document all the ways misuse could thrash the typestate x3
@@ -205,15 +581,50 @@ unsafe fn gl_gen_with<const N: usize, T: ThinGLObject>(
     // NON ZERO value. This requirement is forwarded to the signature of this fn.
     #[cfg(debug_assertions)]
     {
-        let names = core::mem::transmute_copy::<_, core::mem::MaybeUninit<[GLuint; N]>>(&names);
+        // Inspect the names in place as plain `GLuint`s, which may be zero.
+        let names = core::slice::from_raw_parts(names.as_ptr().cast::<GLuint>(), N);
         assert!(
-            !names.assume_init().into_iter().any(|name| name == 0),
-            "gl returned a zeroed texture name, UB abounds."
+            !names.contains(&0),
+            "gl returned a zeroed name, UB abounds."
         );
+
+        #[cfg(feature = "debug-object-tracking")]
+        tracking::track::<T>(names);
     }
 
     names.assume_init()
 }
+/// [`gl_gen_with`], for a count known only at runtime.
+///
+/// # Safety
+/// See [`gl_gen_with`].
+#[cfg(feature = "alloc")]
+unsafe fn gl_gen_vec_with<T: ThinGLObject>(
+    gl_gen: unsafe fn(GLsizei, *mut GLuint),
+    count: usize,
+) -> alloc::vec::Vec<T> {
+    let mut names = alloc::vec::Vec::<T>::with_capacity(count);
+
+    // `cast` here goes from array of something repr(NonZero<GLuint>) to GLuint (Safety precondition of impl ThinGLObject).
+    gl_gen(count.try_into().unwrap(), names.as_mut_ptr().cast());
+
+    #[cfg(debug_assertions)]
+    {
+        // Inspect the names in place as plain `GLuint`s, which may be zero.
+        let names = core::slice::from_raw_parts(names.as_ptr().cast::<GLuint>(), count);
+        assert!(
+            !names.contains(&0),
+            "gl returned a zeroed name, UB abounds."
+        );
+
+        #[cfg(feature = "debug-object-tracking")]
+        tracking::track::<T>(names);
+    }
+
+    // The first `count` elements were initialized with non-zero names, see above.
+    names.set_len(count);
+    names
+}
 /// # Safety
 /// * The context associated with `gl_delete` must be current on the calling thread.
 /// * `gl_delete` must be the appropriate GL deleter for objects of type `T`.
@@ -225,6 +636,9 @@ unsafe fn gl_delete_with<const N: usize, T: ThinGLObject>(
     // Hm. What if usize is smaller than GLsizei?
     const { assert!(N <= GLsizei::MAX as _) };
 
+    #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+    tracking::untrack::<T>(&core::mem::transmute_copy::<_, [GLuint; N]>(&names));
+
     // Cast: impl ThinGLObject is safely interpretable as GLuint
     gl_delete(N as _, names.as_mut_ptr().cast());
 }
@@ -1,6 +1,8 @@
 //! Rust-flavored allocation functions for GL objects.
+#[cfg(feature = "alloc")]
+use crate::gl_gen_vec_with;
 use crate::{
-    buffer, framebuffer, gl, gl_delete_with, gl_gen_with, program, renderbuffer, texture,
+    buffer, framebuffer, gl, gl_delete_with, gl_gen_with, program, query, renderbuffer, texture,
     vertex_array, NonZeroName, NotSync,
 };
 
@@ -15,9 +17,12 @@ use crate::{
 /// Usage:
 /// ```no_run
 /// # let gl : glhf::GLHF = todo!();
-/// let [one_texture] = gl.create.textures();
-/// let [a, bunch, of, framebuffers] = gl.create.framebuffers();
+/// let [one_texture] = gl.new.textures();
+/// let [a, bunch, of, framebuffers] = gl.new.framebuffers();
 /// ```
+///
+/// With the `alloc` feature, the `*_vec` variants generate a number of objects known only at
+/// runtime, still with a single `glGen*` call.
 // Interestingly, many `glGen*`s are *optional* - you can just make up a number
 // and use it. We intentionally don't support this usecase.
 pub struct New(pub(crate) NotSync);
@@ -27,6 +32,12 @@ impl New {
     pub fn textures<const N: usize>(&self) -> [texture::Stateless; N] {
         unsafe { gl_gen_with(gl::GenTextures) }
     }
+    /// Generate `count` new texture objects, as [`Self::textures`].
+    #[doc(alias = "glGenTextures")]
+    #[cfg(feature = "alloc")]
+    pub fn textures_vec(&self, count: usize) -> alloc::vec::Vec<texture::Stateless> {
+        unsafe { gl_gen_vec_with(gl::GenTextures, count) }
+    }
     /// Delete stateless textures. To delete stateful textures, use the
     /// [relavent `Slot` interface](crate::slot::texture::Slot::delete).
     #[doc(alias = "glDeleteTextures")]
@@ -38,21 +49,61 @@ impl New {
     pub fn framebuffers<const N: usize>(&self) -> [framebuffer::Incomplete; N] {
         unsafe { gl_gen_with(gl::GenFramebuffers) }
     }
+    /// Generate `count` new framebuffer objects, as [`Self::framebuffers`].
+    #[doc(alias = "glGenFramebuffers")]
+    #[cfg(feature = "alloc")]
+    pub fn framebuffers_vec(&self, count: usize) -> alloc::vec::Vec<framebuffer::Incomplete> {
+        unsafe { gl_gen_vec_with(gl::GenFramebuffers, count) }
+    }
     /// Generate a set of new vertex array objects.
     #[doc(alias = "glGenVertexArrays")]
     pub fn vertex_arrays<const N: usize>(&self) -> [vertex_array::VertexArray; N] {
         unsafe { gl_gen_with(gl::GenVertexArrays) }
     }
+    /// Generate `count` new vertex array objects, as [`Self::vertex_arrays`].
+    #[doc(alias = "glGenVertexArrays")]
+    #[cfg(feature = "alloc")]
+    pub fn vertex_arrays_vec(&self, count: usize) -> alloc::vec::Vec<vertex_array::VertexArray> {
+        unsafe { gl_gen_vec_with(gl::GenVertexArrays, count) }
+    }
     /// Generate a set of new buffer objects.
     #[doc(alias = "glGenBuffers")]
     pub fn buffers<const N: usize>(&self) -> [buffer::Buffer; N] {
         unsafe { gl_gen_with(gl::GenBuffers) }
     }
+    /// Generate `count` new buffer objects, as [`Self::buffers`].
+    #[doc(alias = "glGenBuffers")]
+    #[cfg(feature = "alloc")]
+    pub fn buffers_vec(&self, count: usize) -> alloc::vec::Vec<buffer::Buffer> {
+        unsafe { gl_gen_vec_with(gl::GenBuffers, count) }
+    }
     /// Generate a set of renderbuffer objects.
     #[doc(alias = "glGenRenderbuffers")]
     pub fn render_buffers<const N: usize>(&self) -> [renderbuffer::Renderbuffer; N] {
         unsafe { gl_gen_with(gl::GenRenderbuffers) }
     }
+    /// Generate `count` new renderbuffer objects, as [`Self::render_buffers`].
+    #[doc(alias = "glGenRenderbuffers")]
+    #[cfg(feature = "alloc")]
+    pub fn render_buffers_vec(&self, count: usize) -> alloc::vec::Vec<renderbuffer::Renderbuffer> {
+        unsafe { gl_gen_vec_with(gl::GenRenderbuffers, count) }
+    }
+    /// Generate a set of new timer query objects.
+    #[doc(alias = "glGenQueries")]
+    pub fn timer_queries<const N: usize>(&self) -> [query::TimerQuery; N] {
+        unsafe { gl_gen_with(gl::GenQueries) }
+    }
+    /// Generate `count` new timer query objects, as [`Self::timer_queries`].
+    #[doc(alias = "glGenQueries")]
+    #[cfg(feature = "alloc")]
+    pub fn timer_queries_vec(&self, count: usize) -> alloc::vec::Vec<query::TimerQuery> {
+        unsafe { gl_gen_vec_with(gl::GenQueries, count) }
+    }
+    /// Delete timer query objects. A query must not be deleted while it is timing.
+    #[doc(alias = "glDeleteQueries")]
+    pub fn delete_timer_queries<const N: usize>(&self, queries: [query::TimerQuery; N]) {
+        unsafe { gl_delete_with(gl::DeleteQueries, queries) }
+    }
     /// Initialize a shader object of the given type.
     /// # Panics
     /// On GL-internal error.
@@ -62,6 +113,8 @@ impl New {
         let name: NonZeroName = value
             .try_into()
             .expect("internal gl error while creating shader");
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::track::<program::EmptyShader<Ty>>(&[name.get()]);
 
         // Safety: Precondition of ThinGLOject.
         unsafe { core::mem::transmute(name) }
@@ -75,6 +128,8 @@ impl New {
         let name: NonZeroName = value
             .try_into()
             .expect("internal gl error while creating program");
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::track::<program::Program>(&[name.get()]);
 
         // Safety: Precondition of ThinGLOject.
         unsafe { core::mem::transmute(name) }
@@ -301,13 +301,116 @@ macro_rules! target {
 
 target!(pub struct Vertex = VERTEX_SHADER);
 target!(pub struct Fragment = FRAGMENT_SHADER);
+// Core in GLES 3.2, otherwise requires `GL_OES_geometry_shader`.
+target!(pub struct Geometry = GEOMETRY_SHADER);
+// Core in GLES 3.2, otherwise requires `GL_OES_tessellation_shader`.
+target!(pub struct TessControl = TESS_CONTROL_SHADER);
+target!(pub struct TessEvaluation = TESS_EVALUATION_SHADER);
+
+/// GLSL ES language versions, for use with [`prelude`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Version {
+    /// GLSL ES 3.00, for GLES 3.0.
+    Es300,
+    /// GLSL ES 3.10, for GLES 3.1.
+    Es310,
+    /// GLSL ES 3.20, for GLES 3.2.
+    Es320,
+}
+
+/// GLSL precision qualifiers, for use with [`prelude`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Precision {
+    Low,
+    Medium,
+    High,
+}
+
+/// The lines every GLSL ES shader begins with - a `#version` directive and a default `float`
+/// precision.
+///
+/// Unlike desktop GLSL, the version is mandatory (otherwise GLSL ES 1.00 is assumed) and fragment
+/// shaders have no default float precision. Use with
+/// [`compile_sources`](crate::slot::program::Slot::compile_sources) to prepend it to a body.
+///
+/// ```
+/// use glhf::program::{prelude, Precision, Version};
+/// assert_eq!(
+///     prelude(Version::Es310, Precision::High),
+///     "#version 310 es\nprecision highp float;\n"
+/// );
+/// ```
+#[must_use]
+pub const fn prelude(version: Version, default_float_precision: Precision) -> &'static str {
+    macro_rules! prelude {
+        ($version:literal, $precision:literal) => {
+            concat!(
+                "#version ",
+                $version,
+                " es\nprecision ",
+                $precision,
+                " float;\n"
+            )
+        };
+    }
+    match (version, default_float_precision) {
+        (Version::Es300, Precision::Low) => prelude!("300", "lowp"),
+        (Version::Es300, Precision::Medium) => prelude!("300", "mediump"),
+        (Version::Es300, Precision::High) => prelude!("300", "highp"),
+        (Version::Es310, Precision::Low) => prelude!("310", "lowp"),
+        (Version::Es310, Precision::Medium) => prelude!("310", "mediump"),
+        (Version::Es310, Precision::High) => prelude!("310", "highp"),
+        (Version::Es320, Precision::Low) => prelude!("320", "lowp"),
+        (Version::Es320, Precision::Medium) => prelude!("320", "mediump"),
+        (Version::Es320, Precision::High) => prelude!("320", "highp"),
+    }
+}
 
 pub enum ProgramShaders<'a> {
     Graphics {
         vertex: &'a CompiledShader<Vertex>,
+        /// Optional stage between vertex and fragment, operating on whole primitives.
+        ///
+        /// Geometry shaders are core in GLES 3.2, and otherwise require
+        /// [`Extension::GeometryShader`](crate::info::Extension::GeometryShader). Where neither is
+        /// available, [creating](crate::new::New::shader) a [`Geometry`] shader panics.
+        geometry: Option<&'a CompiledShader<Geometry>>,
         /// Contrary to OpenGL, OpenGLES requires a fragment shader.
         fragment: &'a CompiledShader<Fragment>,
     },
+    /// A graphics pipeline which subdivides [patches](crate::draw::Topology::Patches)
+    /// between the vertex and geometry stages.
+    ///
+    /// Tessellation shaders are core in GLES 3.2, and otherwise require
+    /// [`Extension::TessellationShader`](crate::info::Extension::TessellationShader). Where neither
+    /// is available, [creating](crate::new::New::shader) a tessellation shader panics.
+    Tessellated {
+        vertex: &'a CompiledShader<Vertex>,
+        /// Sets per-patch tessellation levels.
+        tess_control: &'a CompiledShader<TessControl>,
+        /// Computes the position of each generated vertex.
+        tess_evaluation: &'a CompiledShader<TessEvaluation>,
+        geometry: Option<&'a CompiledShader<Geometry>>,
+        fragment: &'a CompiledShader<Fragment>,
+    },
+}
+
+/// The placement of one active uniform within a uniform block, as reported by
+/// [`Slot::uniform_block_layout`](crate::slot::program::Slot::uniform_block_layout).
+///
+/// All values are in bytes. The block's layout qualifier (`std140`, `shared`, ...) decides them,
+/// which for `shared` and `packed` blocks is only known after linking.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct UniformMember {
+    /// The active uniform index, for use with `glGetActiveUniform`.
+    pub index: u32,
+    /// The offset of the member from the start of the block.
+    pub offset: u32,
+    /// The distance between consecutive elements of an array, or zero if not an array.
+    pub array_stride: u32,
+    /// The distance between consecutive columns (or rows, if row-major) of a matrix, or zero if
+    /// not a matrix.
+    pub matrix_stride: u32,
 }
 
 /// A shader which has no source code.
@@ -329,7 +432,9 @@ impl<Ty: Type> EmptyShader<Ty> {
 impl<Ty: Type> crate::sealed::Sealed for EmptyShader<Ty> {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl<Ty: Type> crate::ThinGLObject for EmptyShader<Ty> {}
+unsafe impl<Ty: Type> crate::ThinGLObject for EmptyShader<Ty> {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Shader;
+}
 
 /// A shader which has been successfully compiled.
 #[repr(transparent)]
@@ -340,7 +445,9 @@ pub struct CompiledShader<Ty: Type>(pub(crate) NonZeroName, core::marker::Phanto
 impl<Ty: Type> crate::sealed::Sealed for CompiledShader<Ty> {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl<Ty: Type> crate::ThinGLObject for CompiledShader<Ty> {}
+unsafe impl<Ty: Type> crate::ThinGLObject for CompiledShader<Ty> {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Shader;
+}
 
 /// Forget the compiled and source code bind status of the shader.
 impl<Ty: Type> From<CompiledShader<Ty>> for EmptyShader<Ty> {
@@ -369,7 +476,9 @@ impl Program {
 impl crate::sealed::Sealed for Program {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for Program {}
+unsafe impl crate::ThinGLObject for Program {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Program;
+}
 
 /// A program which has been successfully linked.
 #[repr(transparent)]
@@ -388,4 +497,11 @@ impl From<LinkedProgram> for Program {
 impl crate::sealed::Sealed for LinkedProgram {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for LinkedProgram {}
+unsafe impl crate::ThinGLObject for LinkedProgram {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Program;
+}
+
+/// Program objects regardless of link status - [`Program`] and [`LinkedProgram`].
+pub trait AnyProgram: crate::ThinGLObject {}
+impl AnyProgram for Program {}
+impl AnyProgram for LinkedProgram {}
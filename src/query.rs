@@ -0,0 +1,52 @@
+//! Types for Query objects, which asynchronously record information about GL commands.
+use crate::{gl, NonZeroName, GLHF};
+
+/// A query object measuring the GPU time taken to execute a span of commands.
+///
+/// Generated by [`New::timer_queries`](crate::new::New::timer_queries), and used with
+/// [`GLHF::timer_query`].
+#[repr(transparent)]
+#[must_use = "dropping a gl handle leaks resources"]
+pub struct TimerQuery(pub(crate) NonZeroName);
+
+impl crate::sealed::Sealed for TimerQuery {}
+// # Safety
+// Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
+unsafe impl crate::ThinGLObject for TimerQuery {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Query;
+}
+
+/// Times every GL command issued through it, until dropped. Created by [`GLHF::timer_query`].
+///
+/// Borrows the [`GLHF`] for its whole lifetime and dereferences to it, such that it may be used
+/// in place of the [`GLHF`] within a scope. This does *not* prevent nesting timer queries, which
+/// the GL forbids - [`GLHF::timer_query`] remains reachable through the scope, and its safety
+/// precondition is what rules out calling it again before this is dropped.
+pub struct TimerScope<'gl> {
+    // Holding the GLHF ensures `Self::drop` has safe access to gl calls due to safety
+    // precondition of `crate::GLHF`.
+    gl: &'gl mut GLHF,
+}
+impl<'gl> TimerScope<'gl> {
+    pub(crate) fn new(gl: &'gl mut GLHF) -> Self {
+        Self { gl }
+    }
+}
+impl core::ops::Deref for TimerScope<'_> {
+    type Target = GLHF;
+    fn deref(&self) -> &Self::Target {
+        self.gl
+    }
+}
+impl core::ops::DerefMut for TimerScope<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.gl
+    }
+}
+impl Drop for TimerScope<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            gl::EndQuery(gl::TIME_ELAPSED_EXT);
+        }
+    }
+}
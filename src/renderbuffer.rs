@@ -167,4 +167,6 @@ impl Renderbuffer {
 impl crate::sealed::Sealed for Renderbuffer {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for Renderbuffer {}
+unsafe impl crate::ThinGLObject for Renderbuffer {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Renderbuffer;
+}
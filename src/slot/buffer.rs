@@ -1,6 +1,6 @@
 //! Binding and manipulating Buffers.
 use crate::{
-    buffer::{usage, Buffer},
+    buffer::{usage, Buffer, RawMapAccess, RawMapHint},
     gl,
     slot::marker::{IsDefault, NotDefault, Unknown},
     GLenum, NotSync, ThinGLObject,
@@ -146,6 +146,121 @@ pub enum UnmapError {
     Lost,
 }
 
+/// The reason a [`try_map`](Active::try_map) failed.
+#[derive(Debug)]
+pub enum MapError {
+    /// The implementation could not allocate memory for the mapping. Mapping a smaller range may
+    /// succeed.
+    OutOfMemory,
+    /// The GL rejected the mapping for some other reason, with the given `glGetError` code.
+    Other(GLenum),
+}
+
+/// Streams values of `T` into successive ranges of a buffer, as is typical for per-frame vertex
+/// or uniform data. Created by [`Active::stream_writer`].
+///
+/// Each [write](Self::write) maps just the range it needs, without waiting on the GL. When the
+/// buffer is full, the writer wraps around to the start and discards the whole datastore, which
+/// lets the GL hand out fresh memory ("orphaning") rather than stalling on draws still reading
+/// the old contents.
+///
+/// The writer holds no borrow of the buffer, so it may persist across frames. It must be
+/// given the same buffer on every write, see the safety section of [`Active::stream_writer`].
+#[derive(Debug)]
+pub struct StreamWriter<T: bytemuck::Pod> {
+    /// Byte offset of the next write.
+    cursor: usize,
+    /// Length of the datastore, in bytes.
+    capacity: usize,
+    _ty: core::marker::PhantomData<fn(&[T])>,
+}
+impl<T: bytemuck::Pod> StreamWriter<T> {
+    /// The byte offset at which the next write will begin, unless it wraps.
+    #[must_use]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+    /// Advance the cursor to the next multiple of `alignment` bytes, e.g. to satisfy
+    /// `GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT` before writing a uniform block.
+    ///
+    /// # Panics
+    /// If `alignment` is zero.
+    pub fn align(&mut self, alignment: usize) -> &mut Self {
+        self.cursor = self.cursor.next_multiple_of(alignment);
+        self
+    }
+    /// Write `values` at the cursor, aligned to `T`, and advance past them. If they do not fit in the
+    /// remaining space, the datastore is discarded and they are written at the start instead.
+    ///
+    /// Returns the byte offset at which `values` begin, for use in attribute or draw offsets.
+    ///
+    /// # Errors
+    /// See [`MapGuard::unmap`]. The writer is left wrapped around, so the next write discards the
+    /// datastore.
+    ///
+    /// # Panics
+    /// * If `values` is larger than the whole buffer.
+    /// * The implementation is out of memory.
+    #[doc(alias = "glMapBufferRange")]
+    #[doc(alias = "GL_MAP_UNSYNCHRONIZED_BIT")]
+    #[doc(alias = "GL_MAP_INVALIDATE_RANGE_BIT")]
+    #[doc(alias = "GL_MAP_INVALIDATE_BUFFER_BIT")]
+    pub fn write<Binding: Target>(
+        &mut self,
+        active: &mut Active<Binding, NotDefault>,
+        values: &[T],
+    ) -> Result<usize, UnmapError> {
+        #[cfg(debug_assertions)]
+        assert_eq!(
+            active.len(),
+            self.capacity,
+            "StreamWriter used with a different buffer"
+        );
+        #[cfg(not(debug_assertions))]
+        let _ = active;
+
+        let bytes: &[u8] = bytemuck::cast_slice(values);
+        assert!(
+            bytes.len() <= self.capacity,
+            "write larger than the stream buffer"
+        );
+        let mut offset = self.cursor.next_multiple_of(core::mem::align_of::<T>());
+        if bytes.is_empty() {
+            return Ok(offset);
+        }
+
+        let hint = if offset + bytes.len() > self.capacity {
+            // Wrap around. Draws may still be reading anywhere in the buffer, so orphan it.
+            offset = 0;
+            gl::MAP_INVALIDATE_BUFFER_BIT
+        } else {
+            // Nothing written since the last orphaning was ever overwritten, so the GL cannot
+            // be reading this range.
+            gl::MAP_INVALIDATE_RANGE_BIT | gl::MAP_UNSYNCHRONIZED_BIT
+        };
+        let success = unsafe {
+            let ptr = gl::MapBufferRange(
+                Binding::TARGET,
+                offset.try_into().unwrap(),
+                bytes.len().try_into().unwrap(),
+                gl::MAP_WRITE_BIT | hint,
+            );
+            assert!(!ptr.is_null());
+            // Safety: mapped range is `bytes.len()` long and write-only, which we never read.
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.cast(), bytes.len());
+            gl::UnmapBuffer(Binding::TARGET) == true.into()
+        };
+
+        if success {
+            self.cursor = offset + bytes.len();
+            Ok(offset)
+        } else {
+            self.cursor = self.capacity;
+            Err(UnmapError::Lost)
+        }
+    }
+}
+
 /// Entry points for `glBuffer*`
 #[derive(Debug)]
 pub struct Active<Slot, Kind>(core::marker::PhantomData<(Kind, Slot)>);
@@ -267,12 +382,14 @@ impl<Binding: Target> Active<Binding, NotDefault> {
     /// Usage:
     /// ```no_run
     /// use glhf::{slot::buffer};
-    /// # let gl : glhf::GLHF = todo!();
+    /// # let mut gl : glhf::GLHF = todo!();
     /// # let buffer : glhf::buffer::Buffer = todo!();
     ///
-    /// gl.buffer.array.bind(&buffer)
-    ///     .map::<buffer::ReadWrite>(..)
-    ///     .fill(10u8);
+    /// unsafe {
+    ///     gl.buffer.array.bind(&buffer)
+    ///         .map::<buffer::ReadWrite>(..)
+    ///         .fill(10u8);
+    /// }
     /// ```
     /// # Alignment
     /// Unfortunately, the GLES API makes no guarantees on the alignment of the returned byte slice. Do
@@ -294,7 +411,26 @@ impl<Binding: Target> Active<Binding, NotDefault> {
     pub unsafe fn map<Access: MapAccess>(
         &mut self,
         range: impl core::ops::RangeBounds<usize>,
-    ) -> MapGuard<Binding, Access> {
+    ) -> MapGuard<'_, Binding, Access> {
+        self.try_map(range).unwrap()
+    }
+    /// Map a range of the buffer, as [`map`](Self::map), reporting a failure to map instead of
+    /// panicking.
+    ///
+    /// This allows recovering from memory pressure, e.g. by retrying with a smaller range.
+    ///
+    /// A range extending beyond the end of the datastore fails with [`MapError::Other`].
+    ///
+    /// # Panics
+    /// If the range end is before the beginning.
+    ///
+    /// # Safety
+    /// See [`map`](Self::map).
+    #[doc(alias = "glMapBufferRange")]
+    pub unsafe fn try_map<Access: MapAccess>(
+        &mut self,
+        range: impl core::ops::RangeBounds<usize>,
+    ) -> Result<MapGuard<'_, Binding, Access>, MapError> {
         use core::ops::Bound;
         let left = range.start_bound().cloned();
         let right = range.end_bound().cloned();
@@ -320,7 +456,7 @@ impl<Binding: Target> Active<Binding, NotDefault> {
         &mut self,
         offset: usize,
         len: usize,
-    ) -> MapGuard<Binding, Access> {
+    ) -> Result<MapGuard<'_, Binding, Access>, MapError> {
         let ptr = unsafe {
             gl::MapBufferRange(
                 Binding::TARGET,
@@ -329,12 +465,40 @@ impl<Binding: Target> Active<Binding, NotDefault> {
                 Access::FLAGS,
             )
         };
-        assert!(!ptr.is_null());
-        MapGuard {
+        if ptr.is_null() {
+            return Err(match unsafe { gl::GetError() } {
+                gl::OUT_OF_MEMORY => MapError::OutOfMemory,
+                error => MapError::Other(error),
+            });
+        }
+        Ok(MapGuard {
             _active: self,
             access: core::marker::PhantomData,
             ptr: ptr.cast(),
             len,
+        })
+    }
+    /// Create a [`StreamWriter`] which writes into this buffer's whole datastore.
+    ///
+    /// The first write discards the datastore, so the GL may still be reading the buffer's prior
+    /// contents. This invokes a `glGet` to fetch the length of the buffer.
+    ///
+    /// # Safety
+    /// Writes are unsynchronized with the GL, and rely on the writer alone deciding which ranges
+    /// are in use. For as long as the writer is used:
+    /// * It must be given this buffer, and no other, on every write.
+    /// * The datastore must not be reallocated or written through any other means.
+    /// * Draws must only read ranges written by the writer since it last wrapped around, i.e.
+    ///   no offsets from before the wrap may be reused.
+    ///
+    /// Violating these may overwrite data the GL is reading concurrently.
+    pub unsafe fn stream_writer<T: bytemuck::Pod>(&self) -> StreamWriter<T> {
+        let capacity = self.len();
+        StreamWriter {
+            // Start out full, such that the first write wraps around and orphans.
+            cursor: capacity,
+            capacity,
+            _ty: core::marker::PhantomData,
         }
     }
     /// This is not cached and invokes a `glGet`.
@@ -358,6 +522,69 @@ impl<Binding: Target> Active<Binding, NotDefault> {
         };
         len.try_into().unwrap()
     }
+    /// Query whether the buffer is currently mapped.
+    ///
+    /// While a [`MapGuard`] is alive, this `Active` is borrowed - this is mostly useful to check
+    /// assumptions about buffers mapped outside of this crate.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetBufferParameteriv")]
+    #[doc(alias = "GL_BUFFER_MAPPED")]
+    #[must_use]
+    pub fn is_mapped(&self) -> bool {
+        let mapped = unsafe {
+            let mut mapped = core::mem::MaybeUninit::uninit();
+            gl::GetBufferParameteriv(Binding::TARGET, gl::BUFFER_MAPPED, mapped.as_mut_ptr());
+            mapped.assume_init()
+        };
+        mapped == gl::TRUE.into()
+    }
+    /// Get the offset in bytes of the mapped range, or zero if the buffer is not mapped.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetBufferParameteri64v")]
+    #[doc(alias = "GL_BUFFER_MAP_OFFSET")]
+    #[must_use]
+    pub fn map_offset(&self) -> usize {
+        let offset = unsafe {
+            let mut offset = core::mem::MaybeUninit::uninit();
+            gl::GetBufferParameteri64v(Binding::TARGET, gl::BUFFER_MAP_OFFSET, offset.as_mut_ptr());
+            offset.assume_init()
+        };
+        offset.try_into().unwrap()
+    }
+    /// Get the length in bytes of the mapped range, or zero if the buffer is not mapped.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetBufferParameteri64v")]
+    #[doc(alias = "GL_BUFFER_MAP_LENGTH")]
+    #[must_use]
+    pub fn map_length(&self) -> usize {
+        let len = unsafe {
+            let mut len = core::mem::MaybeUninit::uninit();
+            gl::GetBufferParameteri64v(Binding::TARGET, gl::BUFFER_MAP_LENGTH, len.as_mut_ptr());
+            len.assume_init()
+        };
+        len.try_into().unwrap()
+    }
+    /// Get the access and hint flags of the current mapping, or empty flags if the buffer is not
+    /// mapped.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetBufferParameteriv")]
+    #[doc(alias = "GL_BUFFER_ACCESS_FLAGS")]
+    #[must_use]
+    pub fn map_access(&self) -> (RawMapAccess, RawMapHint) {
+        let flags = unsafe {
+            let mut flags = core::mem::MaybeUninit::uninit();
+            gl::GetBufferParameteriv(Binding::TARGET, gl::BUFFER_ACCESS_FLAGS, flags.as_mut_ptr());
+            flags.assume_init()
+        } as gl::types::GLbitfield;
+        (
+            RawMapAccess::from_bits_truncate(flags),
+            RawMapHint::from_bits_truncate(flags),
+        )
+    }
     /// Get the usage hints used at the time of the datastore's allocation.
     ///
     /// This is not cached and invokes a `glGet`.
@@ -398,6 +625,8 @@ impl<Binding: Target> Slot<Binding> {
     /// Bind a buffer to this slot.
     #[doc(alias = "glBindBuffer")]
     pub fn bind(&mut self, buffer: &Buffer) -> &mut Active<Binding, NotDefault> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(buffer);
         unsafe {
             gl::BindBuffer(Binding::TARGET, buffer.name().get());
         }
@@ -438,6 +667,29 @@ pub struct Slots {
     pub uniform: Slot<Uniform>,
 }
 impl Slots {
+    /// Copy bytes from `source` into `dest`, using the [`CopyRead`] and [`CopyWrite`] scratch
+    /// slots so that no other bindings are disturbed.
+    ///
+    /// Afterwards, `source` is left bound to [`Self::copy_read`] and `dest` to
+    /// [`Self::copy_write`]. `source` and `dest` may be the same buffer, in which case the
+    /// regions must not overlap.
+    ///
+    /// Neither the read nor write ranges may extend past the end of their respective buffers.
+    #[doc(alias = "glCopyBufferSubData")]
+    pub fn copy_region(
+        &mut self,
+        source: &Buffer,
+        dest: &Buffer,
+        read_offset: usize,
+        write_offset: usize,
+        len: usize,
+    ) -> &mut Self {
+        let source = self.copy_read.bind(source);
+        self.copy_write
+            .bind(dest)
+            .copy_from(source, read_offset, write_offset, len);
+        self
+    }
     /// Delete buffers. If any were bound to a slot, the slot becomes unbound.
     #[doc(alias = "glDeleteBuffers")]
     pub fn delete<const N: usize>(&mut self, buffers: [Buffer; N]) {
@@ -1,10 +1,14 @@
 //! Binding and manipulating Framebuffers.
 use crate::{
-    framebuffer::{Attachment, Buffer, Complete, DefaultBuffer, Incomplete},
+    framebuffer::{
+        Attachment, AttachmentKind, AttachmentSource, Buffer, Complete, ComponentType,
+        DefaultBuffer, Incomplete,
+    },
     gl,
     renderbuffer::Renderbuffer,
+    slot::buffer::PixelPack,
     slot::marker::{Defaultness, IsDefault, NotDefault, Unknown},
-    texture::{Dimensionality, Texture2D},
+    texture::{DataType, Dimensionality, Format, ImageDataMut, Texture2D},
     GLEnum, GLenum, NotSync, ThinGLObject,
 };
 
@@ -55,6 +59,7 @@ bitflags::bitflags! {
 ///
 /// One rectangle may be the mirror of the other, which will cause the transferred
 /// image to be flipped.
+#[derive(Copy, Clone)]
 struct BlitRectangle {
     /// Lower bound, inclusive.
     from: [i32; 2],
@@ -71,6 +76,46 @@ pub struct BlitInfo {
     /// If this contains Depth or Stencil, [`Self::filter`] must be `Nearest`.
     mask: AspectMask,
 }
+impl BlitInfo {
+    /// A 1:1 copy of the color region from `[0, 0]` to `size`, without scaling or flipping.
+    #[must_use]
+    pub fn full(size: [u32; 2]) -> Self {
+        let rect = BlitRectangle {
+            from: [0; 2],
+            to_exclusive: size.map(|size| size.try_into().unwrap()),
+        };
+        Self {
+            read: rect,
+            write: rect,
+            // No scaling occurs, so the filter is irrelevant.
+            filter: crate::texture::Filter::Nearest,
+            mask: AspectMask::COLOR,
+        }
+    }
+    /// A copy of the color region from `[0, 0]` to `size`, without scaling, mirrored vertically.
+    ///
+    /// The GL places the origin of a framebuffer in the bottom-left, so this is the transform
+    /// needed to present to a surface which expects its origin in the top-left, or vice versa.
+    #[must_use]
+    pub fn flipped_y(size: [u32; 2]) -> Self {
+        let mut this = Self::full(size);
+        let [_, height] = this.write.to_exclusive;
+        this.write.from[1] = height;
+        this.write.to_exclusive[1] = 0;
+        this
+    }
+    /// Whether the blit will mirror the image along the `[x, y]` axes, respectively.
+    ///
+    /// An axis is mirrored when the read and write rectangles disagree on its direction.
+    #[must_use]
+    pub fn flips(&self) -> [bool; 2] {
+        [0, 1].map(|axis| {
+            let reads_forward = self.read.from[axis] <= self.read.to_exclusive[axis];
+            let writes_forward = self.write.from[axis] <= self.write.to_exclusive[axis];
+            reads_forward != writes_forward
+        })
+    }
+}
 
 /// Entry points for `glFramebuffer*`
 #[derive(Debug)]
@@ -100,6 +145,24 @@ impl<T: Target> Active<T, NotDefault, Incomplete> {
         }
         self
     }
+    /// Bind the base level of a combined depth-stencil texture to [`Attachment::DepthStencil`].
+    ///
+    /// Unlike [`Self::texture_2d`], this checks in debug builds that the texture has both aspects,
+    /// i.e. that it is
+    /// [`InternalFormat::Depth24Stencil8`](crate::texture::InternalFormat::Depth24Stencil8) or
+    /// [`InternalFormat::Depth32fStencil8`](crate::texture::InternalFormat::Depth32fStencil8).
+    /// The check is skipped if level parameters cannot be queried (GLES 3.0) or the base level is
+    /// undefined.
+    ///
+    /// # Panics
+    /// In debug builds, if the texture lacks a depth or stencil aspect.
+    #[doc(alias = "glFramebufferTexture2D")]
+    #[doc(alias = "GL_DEPTH_STENCIL_ATTACHMENT")]
+    pub fn attach_depth_stencil(&mut self, texture: &Texture2D) -> &mut Self {
+        #[cfg(debug_assertions)]
+        assert_depth_stencil(texture);
+        self.texture_2d(texture, Attachment::DepthStencil, 0)
+    }
     /// Bind a Renderbuffer to the given `attachment` point.
     ///
     /// Renderbuffers are generally more efficient than textures, but are mostly opaque to
@@ -133,6 +196,75 @@ impl<T: Target> Active<T, NotDefault, Incomplete> {
         }
         self
     }
+    /// Set the dimensions assumed for rasterization when the framebuffer has no attachments.
+    /// Requires GLES 3.1.
+    ///
+    /// A framebuffer with no attachments is [complete](Slot::try_complete) if its default width
+    /// and height are nonzero, rather than failing with
+    /// [`IncompleteErrorKind::MissingAttachment`]. Fragments are still rasterized, which is
+    /// useful for passes that only write through image stores or atomic counters.
+    ///
+    /// `layers` requires GLES 3.2, and is left unchanged if zero.
+    ///
+    /// # Panics
+    /// If the GLES 3.1 entry point was not loaded.
+    #[doc(alias = "glFramebufferParameteri")]
+    #[doc(alias = "GL_FRAMEBUFFER_DEFAULT_WIDTH")]
+    #[doc(alias = "GL_FRAMEBUFFER_DEFAULT_HEIGHT")]
+    #[doc(alias = "GL_FRAMEBUFFER_DEFAULT_LAYERS")]
+    #[doc(alias = "GL_FRAMEBUFFER_DEFAULT_SAMPLES")]
+    pub fn default_dimensions(
+        &mut self,
+        width: u32,
+        height: u32,
+        layers: u32,
+        samples: u32,
+    ) -> &mut Self {
+        let set = |pname, value: u32| unsafe {
+            gl::FramebufferParameteri(T::TARGET, pname, value.try_into().unwrap());
+        };
+        set(gl::FRAMEBUFFER_DEFAULT_WIDTH, width);
+        set(gl::FRAMEBUFFER_DEFAULT_HEIGHT, height);
+        if layers != 0 {
+            set(gl::FRAMEBUFFER_DEFAULT_LAYERS, layers);
+        }
+        set(gl::FRAMEBUFFER_DEFAULT_SAMPLES, samples);
+        self
+    }
+    /// Check completeness of `framebuffer`, which is already bound to this slot.
+    ///
+    /// This is [`Slot::try_complete`] without the redundant bind, for use directly after
+    /// attaching.
+    ///
+    /// On failure, the incomplete framebuffer is returned unchanged.
+    ///
+    /// # Safety
+    /// `framebuffer` must be the framebuffer bound to this slot, otherwise the completeness of
+    /// a different framebuffer is attributed to it. This is checked in debug builds.
+    ///
+    /// # Panics
+    /// See [`Slot::try_complete`].
+    #[doc(alias = "glCheckFramebufferStatus")]
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn check_complete(
+        &mut self,
+        framebuffer: Incomplete,
+    ) -> Result<(Complete, &mut Active<T, NotDefault, Complete>), IncompleteError<'_, T>> {
+        #[cfg(debug_assertions)]
+        {
+            let binding = unsafe {
+                let mut binding = core::mem::MaybeUninit::uninit();
+                gl::GetIntegerv(binding_pname::<T>(), binding.as_mut_ptr());
+                binding.assume_init()
+            };
+            assert_eq!(
+                binding as gl::types::GLuint,
+                framebuffer.name().get(),
+                "check_complete() with a framebuffer that is not bound"
+            );
+        }
+        Slot::check_bound(self, framebuffer)
+    }
 }
 
 impl<AnyDefaultness: Defaultness> Active<Draw, AnyDefaultness, Complete> {
@@ -141,6 +273,10 @@ impl<AnyDefaultness: Defaultness> Active<Draw, AnyDefaultness, Complete> {
     /// The read buffer's current color attachment ([`Active::read_buffer`]) is copied
     /// to each of this buffer's [`Active::draw_buffers`].
     ///
+    /// # Panics
+    /// In debug builds, if blitting color and the read buffer names an attachment with nothing
+    /// attached.
+    ///
     /// # Safety
     /// If the read buffer and any of the draw buffers refer to the same resource and the source
     /// and destination rectangles overlap, behavior is undefined.
@@ -153,6 +289,10 @@ impl<AnyDefaultness: Defaultness> Active<Draw, AnyDefaultness, Complete> {
         if info.mask.is_empty() {
             return self;
         }
+        #[cfg(debug_assertions)]
+        if info.mask.contains(AspectMask::COLOR) {
+            assert_read_buffer_attached("blit_from");
+        }
         unsafe {
             gl::BlitFramebuffer(
                 info.read.from[0],
@@ -173,21 +313,440 @@ impl<AnyDefaultness: Defaultness> Active<Draw, AnyDefaultness, Complete> {
 
         self
     }
+    /// Resolve a multisampled read framebuffer into this one, copying the color region from
+    /// `[0, 0]` to `size` in both.
+    ///
+    /// For the resolve to succeed, the two framebuffers must agree on the internal format of
+    /// their color buffers, and this framebuffer must not be multisampled. Otherwise, this will
+    /// generate `GL_INVALID_OPERATION` and do nothing.
+    ///
+    /// # Safety
+    /// See [`Self::blit_from`].
+    #[doc(alias = "glBlitFramebuffer")]
+    pub unsafe fn resolve_from<OtherDefaultness: Defaultness>(
+        &mut self,
+        msaa: &Active<Read, OtherDefaultness, Complete>,
+        size: [u32; 2],
+    ) -> &mut Self {
+        // Nearest is required for a resolve anyway.
+        self.blit_from(msaa, &BlitInfo::full(size))
+    }
+    /// Copy the color region from `[0, 0]` to `size` of the read framebuffer into the same region
+    /// of this one, as is typical when compositing an offscreen framebuffer onto the screen.
+    ///
+    /// This is shorthand for [`Self::blit_from`] with [`BlitInfo::full`].
+    ///
+    /// # Safety
+    /// See [`Self::blit_from`].
+    #[doc(alias = "glBlitFramebuffer")]
+    pub unsafe fn blit_full<OtherDefaultness: Defaultness>(
+        &mut self,
+        from: &Active<Read, OtherDefaultness, Complete>,
+        size: [u32; 2],
+    ) -> &mut Self {
+        self.blit_from(from, &BlitInfo::full(size))
+    }
+    /// Copy the color region from `[0, 0]` to `size` of the read framebuffer into the same region
+    /// of this one, mirrored vertically. Use this when presenting an offscreen render to a surface
+    /// whose origin is in the top-left rather than the GL's bottom-left.
+    ///
+    /// This is shorthand for [`Self::blit_from`] with [`BlitInfo::flipped_y`].
+    ///
+    /// # Safety
+    /// See [`Self::blit_from`].
+    #[doc(alias = "glBlitFramebuffer")]
+    pub unsafe fn blit_flipped_y<OtherDefaultness: Defaultness>(
+        &mut self,
+        from: &Active<Read, OtherDefaultness, Complete>,
+        size: [u32; 2],
+    ) -> &mut Self {
+        self.blit_from(from, &BlitInfo::flipped_y(size))
+    }
     /// Clear color, depth, and/or stencil buffers. Aspects not contained in the framebuffer are ignored.
     ///
     /// Affected color buffers are limited to those selected by [`Self::draw_buffers`].
     ///
     /// The clear values are inherited from the global values `ClearColor`, `ClearDepth`, and `ClearStencil`.
+    /// Use [`Self::clear_to`] to give the values directly instead.
+    ///
+    /// Clearing an integer color buffer this way leaves it undefined - use [`Self::clear_color_i32`] or
+    /// [`Self::clear_color_u32`] instead.
+    ///
+    /// # Panics
+    /// In debug builds:
+    /// * If `mask` contains `COLOR` and any selected color buffer is an integer format.
+    /// * If any aspect of `mask` is missing from the framebuffer, as the clear would silently do
+    ///   nothing. For the default framebuffer, only depth and stencil are checked, see
+    ///   [`Active::has_depth`] and [`Active::has_stencil`].
     #[doc(alias = "glClear")]
     pub fn clear(&mut self, mask: AspectMask) -> &mut Self {
         if mask.is_empty() {
             return self;
         }
+        #[cfg(debug_assertions)]
+        {
+            assert_aspects_attached(&mask);
+            if mask.contains(AspectMask::COLOR) {
+                assert_no_integer_draw_buffers();
+            }
+        }
         unsafe {
             gl::Clear(mask.bits());
         }
         self
     }
+    /// Clear the color buffer selected by `draw_buffer`, an index into [`Self::draw_buffers`], to `color`.
+    /// This is the only way to clear a floating or normalized color buffer without touching the global
+    /// clear color.
+    #[doc(alias = "glClearBufferfv")]
+    pub fn clear_color_f32(&mut self, draw_buffer: u32, color: [f32; 4]) -> &mut Self {
+        unsafe {
+            gl::ClearBufferfv(gl::COLOR, draw_buffer.try_into().unwrap(), color.as_ptr());
+        }
+        self
+    }
+    /// Clear the signed integer color buffer selected by `draw_buffer`, an index into
+    /// [`Self::draw_buffers`], to `color`.
+    #[doc(alias = "glClearBufferiv")]
+    pub fn clear_color_i32(&mut self, draw_buffer: u32, color: [i32; 4]) -> &mut Self {
+        unsafe {
+            gl::ClearBufferiv(gl::COLOR, draw_buffer.try_into().unwrap(), color.as_ptr());
+        }
+        self
+    }
+    /// Clear the unsigned integer color buffer selected by `draw_buffer`, an index into
+    /// [`Self::draw_buffers`], to `color`.
+    #[doc(alias = "glClearBufferuiv")]
+    pub fn clear_color_u32(&mut self, draw_buffer: u32, color: [u32; 4]) -> &mut Self {
+        unsafe {
+            gl::ClearBufferuiv(gl::COLOR, draw_buffer.try_into().unwrap(), color.as_ptr());
+        }
+        self
+    }
+    /// Clear the depth and stencil buffers, without touching the global clear values.
+    ///
+    /// Only the low bits of `stencil` are written, according to the size of the stencil buffer.
+    #[doc(alias = "glClearBufferfi")]
+    pub fn clear_depth_stencil(&mut self, depth: f32, stencil: u32) -> &mut Self {
+        unsafe {
+            gl::ClearBufferfi(gl::DEPTH_STENCIL, 0, depth, stencil as _);
+        }
+        self
+    }
+    /// Clear any of color, depth, and stencil to the given values, as [`Self::clear`] but without
+    /// reading or touching the global clear values. Aspects given as `None` are left as-is.
+    ///
+    /// `color` is written to every color buffer selected by [`Self::draw_buffers`]. For integer
+    /// color buffers, use [`Self::clear_color_i32`] or [`Self::clear_color_u32`] instead.
+    ///
+    /// # Panics
+    /// In debug builds, as [`Self::clear`] would for the aspects given.
+    #[doc(alias = "glClearBufferfv")]
+    #[doc(alias = "glClearBufferiv")]
+    #[doc(alias = "glClearBufferfi")]
+    pub fn clear_to(
+        &mut self,
+        color: Option<crate::state::Color>,
+        depth: Option<f32>,
+        stencil: Option<u32>,
+    ) -> &mut Self {
+        #[cfg(debug_assertions)]
+        {
+            let mut mask = AspectMask::empty();
+            mask.set(AspectMask::COLOR, color.is_some());
+            mask.set(AspectMask::DEPTH, depth.is_some());
+            mask.set(AspectMask::STENCIL, stencil.is_some());
+            assert_aspects_attached(&mask);
+            if color.is_some() {
+                assert_no_integer_draw_buffers();
+            }
+        }
+        if let Some(crate::state::Color { r, g, b, a }) = color {
+            // Only four color attachments are supported, and so only four draw buffers can be
+            // selected. The GL ignores those set to `GL_NONE`.
+            for draw_buffer in 0..4 {
+                self.clear_color_f32(draw_buffer, [r, g, b, a]);
+            }
+        }
+        match (depth, stencil) {
+            (Some(depth), Some(stencil)) => {
+                self.clear_depth_stencil(depth, stencil);
+            }
+            (Some(depth), None) => unsafe {
+                gl::ClearBufferfv(gl::DEPTH, 0, &depth);
+            },
+            (None, Some(stencil)) => unsafe {
+                gl::ClearBufferiv(gl::STENCIL, 0, &(stencil as _));
+            },
+            (None, None) => (),
+        }
+        self
+    }
+}
+
+/// Query the size of the image selected by the read buffer of the bound read framebuffer, or `None`
+/// if it can't be determined - e.g. for the default framebuffer, whose size is known only to the
+/// window system.
+///
+/// Bindings which are disturbed by the query are restored.
+#[cfg(debug_assertions)]
+fn read_buffer_extent() -> Option<[u32; 2]> {
+    let get = |pname| unsafe {
+        let mut value = core::mem::MaybeUninit::uninit();
+        gl::GetIntegerv(pname, value.as_mut_ptr());
+        value.assume_init()
+    };
+    if get(gl::READ_FRAMEBUFFER_BINDING) == 0 {
+        return None;
+    }
+    // Just checked, a user-defined framebuffer is bound.
+    type ReadActive = Active<Read, NotDefault, Complete>;
+    let attachment = match get(gl::READ_BUFFER) as GLenum {
+        gl::COLOR_ATTACHMENT0 => Attachment::Color0,
+        gl::COLOR_ATTACHMENT1 => Attachment::Color1,
+        gl::COLOR_ATTACHMENT2 => Attachment::Color2,
+        gl::COLOR_ATTACHMENT3 => Attachment::Color3,
+        _ => return None,
+    };
+    let kind = super::zst_ref::<ReadActive>().attachment_kind(attachment);
+    let name = unsafe {
+        ReadActive::attachment_parameter(attachment, gl::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME)
+    };
+    let mut extent = [0; 2];
+    match kind {
+        AttachmentKind::None => return None,
+        AttachmentKind::Renderbuffer => unsafe {
+            let previous = get(gl::RENDERBUFFER_BINDING);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, name);
+            gl::GetRenderbufferParameteriv(
+                gl::RENDERBUFFER,
+                gl::RENDERBUFFER_WIDTH,
+                &mut extent[0],
+            );
+            gl::GetRenderbufferParameteriv(
+                gl::RENDERBUFFER,
+                gl::RENDERBUFFER_HEIGHT,
+                &mut extent[1],
+            );
+            gl::BindRenderbuffer(gl::RENDERBUFFER, previous as _);
+        },
+        AttachmentKind::Texture => unsafe {
+            // Level parameter queries require GLES 3.1.
+            if !gl::GetTexLevelParameteriv::is_loaded() {
+                return None;
+            }
+            let level = ReadActive::attachment_parameter(
+                attachment,
+                gl::FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL,
+            ) as _;
+            // Only 2D textures may be attached through this crate.
+            let previous = get(gl::TEXTURE_BINDING_2D);
+            gl::BindTexture(gl::TEXTURE_2D, name);
+            gl::GetTexLevelParameteriv(gl::TEXTURE_2D, level, gl::TEXTURE_WIDTH, &mut extent[0]);
+            gl::GetTexLevelParameteriv(gl::TEXTURE_2D, level, gl::TEXTURE_HEIGHT, &mut extent[1]);
+            gl::BindTexture(gl::TEXTURE_2D, previous as _);
+        },
+    }
+    Some(extent.map(|n| n.try_into().unwrap()))
+}
+/// Panic if the base level of `texture` is defined with a format lacking a depth or stencil
+/// aspect.
+///
+/// Skipped if level parameters cannot be queried (GLES 3.0) or the base level is undefined.
+#[cfg(debug_assertions)]
+fn assert_depth_stencil(texture: &Texture2D) {
+    if !gl::GetTexLevelParameteriv::is_loaded() {
+        return;
+    }
+    let (width, internal_format) = unsafe {
+        let (mut previous, mut width, mut internal_format) = (0, 0, 0);
+        gl::GetIntegerv(gl::TEXTURE_BINDING_2D, &mut previous);
+        gl::BindTexture(gl::TEXTURE_2D, texture.name().get());
+        gl::GetTexLevelParameteriv(gl::TEXTURE_2D, 0, gl::TEXTURE_WIDTH, &mut width);
+        gl::GetTexLevelParameteriv(
+            gl::TEXTURE_2D,
+            0,
+            gl::TEXTURE_INTERNAL_FORMAT,
+            &mut internal_format,
+        );
+        gl::BindTexture(gl::TEXTURE_2D, previous as _);
+        (width, internal_format as GLenum)
+    };
+    if width == 0 {
+        return;
+    }
+    assert!(
+        matches!(
+            internal_format,
+            gl::DEPTH24_STENCIL8 | gl::DEPTH32F_STENCIL8
+        ),
+        "attach_depth_stencil() with a texture that is not depth-stencil"
+    );
+}
+/// The `glGet` parameter for the framebuffer bound to `T`.
+#[cfg(debug_assertions)]
+fn binding_pname<T: Target>() -> GLenum {
+    if T::TARGET == gl::DRAW_FRAMEBUFFER {
+        gl::DRAW_FRAMEBUFFER_BINDING
+    } else {
+        gl::READ_FRAMEBUFFER_BINDING
+    }
+}
+/// Panic if `attachment` of the user-defined framebuffer bound to `T` has nothing attached.
+#[cfg(debug_assertions)]
+fn assert_buffer_attached<T: Target>(call: &str, attachment: Attachment) {
+    assert_ne!(
+        super::zst_ref::<Active<T, NotDefault, Complete>>().attachment_kind(attachment),
+        AttachmentKind::None,
+        "{call}() selects empty attachment {attachment:?}"
+    );
+}
+/// Check that the draw buffers of the framebuffer bound for drawing, beyond the first, select
+/// populated attachments.
+///
+/// These are `GL_NONE` unless [explicitly selected](Active::draw_buffers). The first draw buffer
+/// is skipped, as it selects [`Attachment::Color0`] by default, which may validly be empty.
+#[cfg(debug_assertions)]
+fn assert_selected_draw_buffers_attached() {
+    // Only four color attachments are supported, and so only four draw buffers can be selected.
+    for draw_buffer in 1..4 {
+        let mut value = 0;
+        unsafe { gl::GetIntegerv(gl::DRAW_BUFFER0 + draw_buffer, &mut value) };
+        if let Some(attachment) = color_attachment_from_gl(value as GLenum) {
+            assert_buffer_attached::<Draw>("draw_buffers", attachment);
+        }
+    }
+}
+/// Check that the read buffer of the bound read framebuffer selects a populated attachment, as
+/// reading from an empty one is an error.
+#[cfg(debug_assertions)]
+fn assert_read_buffer_attached(call: &str) {
+    let get = |pname| unsafe {
+        let mut value = 0;
+        gl::GetIntegerv(pname, &mut value);
+        value
+    };
+    if get(gl::READ_FRAMEBUFFER_BINDING) == 0 {
+        return;
+    }
+    if let Some(attachment) = color_attachment_from_gl(get(gl::READ_BUFFER) as GLenum) {
+        assert_ne!(
+            super::zst_ref::<Active<Read, NotDefault, Complete>>().attachment_kind(attachment),
+            AttachmentKind::None,
+            "{call}() from read buffer {attachment:?} with nothing attached"
+        );
+    }
+}
+/// Decode a `GL_COLOR_ATTACHMENTi` selected as a draw or read buffer.
+#[cfg(debug_assertions)]
+fn color_attachment_from_gl(value: GLenum) -> Option<Attachment> {
+    match value {
+        gl::COLOR_ATTACHMENT0 => Some(Attachment::Color0),
+        gl::COLOR_ATTACHMENT1 => Some(Attachment::Color1),
+        gl::COLOR_ATTACHMENT2 => Some(Attachment::Color2),
+        gl::COLOR_ATTACHMENT3 => Some(Attachment::Color3),
+        _ => None,
+    }
+}
+/// Check that a read of `size` texels at `offset` lies within the current read buffer, if its
+/// extent can be determined.
+#[cfg(debug_assertions)]
+fn assert_read_in_bounds(offset: [i64; 2], size: [u32; 2]) {
+    let Some(extent) = read_buffer_extent() else {
+        return;
+    };
+    let in_bounds = (0..2).all(|axis| {
+        offset[axis] >= 0 && offset[axis] + i64::from(size[axis]) <= i64::from(extent[axis])
+    });
+    assert!(
+        in_bounds,
+        "read of {size:?} texels at {offset:?} is outside of the {extent:?} read buffer"
+    );
+}
+/// Check that every aspect of `mask` has an image attached to the bound draw framebuffer, as
+/// `glClear` ignores the rest.
+#[cfg(debug_assertions)]
+fn assert_aspects_attached(mask: &AspectMask) {
+    let get = |pname| unsafe {
+        let mut value = core::mem::MaybeUninit::uninit();
+        gl::GetIntegerv(pname, value.as_mut_ptr());
+        value.assume_init()
+    };
+    if get(gl::DRAW_FRAMEBUFFER_BINDING) == 0 {
+        // Just checked, the default framebuffer is bound.
+        let active = super::zst_ref::<Active<Draw, IsDefault, Complete>>();
+        assert!(
+            !mask.contains(AspectMask::DEPTH) || active.has_depth(),
+            "clear() of DEPTH on a default framebuffer with no depth buffer"
+        );
+        assert!(
+            !mask.contains(AspectMask::STENCIL) || active.has_stencil(),
+            "clear() of STENCIL on a default framebuffer with no stencil buffer"
+        );
+        return;
+    }
+    // Just checked, a user-defined framebuffer is bound.
+    let active = super::zst_ref::<Active<Draw, NotDefault, Complete>>();
+    let is_attached = |attachment| active.attachment_kind(attachment) != AttachmentKind::None;
+    if mask.contains(AspectMask::COLOR) {
+        // Only four color attachments are supported, and so only four draw buffers can be selected.
+        let any_color = (0..4).any(|draw_buffer| {
+            let attachment = match get(gl::DRAW_BUFFER0 + draw_buffer) as GLenum {
+                gl::COLOR_ATTACHMENT0 => Attachment::Color0,
+                gl::COLOR_ATTACHMENT1 => Attachment::Color1,
+                gl::COLOR_ATTACHMENT2 => Attachment::Color2,
+                gl::COLOR_ATTACHMENT3 => Attachment::Color3,
+                _ => return false,
+            };
+            is_attached(attachment)
+        });
+        assert!(
+            any_color,
+            "clear() of COLOR with no attached color buffer selected by draw_buffers"
+        );
+    }
+    // A depth-stencil image is reported at both of these points.
+    assert!(
+        !mask.contains(AspectMask::DEPTH) || is_attached(Attachment::Depth),
+        "clear() of DEPTH with no depth attachment"
+    );
+    assert!(
+        !mask.contains(AspectMask::STENCIL) || is_attached(Attachment::Stencil),
+        "clear() of STENCIL with no stencil attachment"
+    );
+}
+/// Check that no color buffer selected by the bound draw framebuffer is of an integer format, as
+/// `glClear` leaves those undefined.
+#[cfg(debug_assertions)]
+fn assert_no_integer_draw_buffers() {
+    let get = |pname| unsafe {
+        let mut value = core::mem::MaybeUninit::uninit();
+        gl::GetIntegerv(pname, value.as_mut_ptr());
+        value.assume_init()
+    };
+    if get(gl::DRAW_FRAMEBUFFER_BINDING) == 0 {
+        // The default framebuffer has no integer buffers.
+        return;
+    }
+    // Just checked, a user-defined framebuffer is bound.
+    let active = super::zst_ref::<Active<Draw, NotDefault, Complete>>();
+    // Only four color attachments are supported, and so only four draw buffers can be selected.
+    for draw_buffer in 0..4 {
+        let attachment = match get(gl::DRAW_BUFFER0 + draw_buffer) as GLenum {
+            gl::COLOR_ATTACHMENT0 => Attachment::Color0,
+            gl::COLOR_ATTACHMENT1 => Attachment::Color1,
+            gl::COLOR_ATTACHMENT2 => Attachment::Color2,
+            gl::COLOR_ATTACHMENT3 => Attachment::Color3,
+            _ => continue,
+        };
+        assert!(
+            !matches!(
+                active.attachment_component_type(attachment),
+                Some(ComponentType::Int | ComponentType::UnsignedInt)
+            ),
+            "clear() of integer color attachment {attachment:?} is undefined, use `clear_color_i32` or `clear_color_u32`"
+        );
+    }
 }
 impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
     /// Blit data from this buffer into the write buffer.
@@ -204,6 +763,110 @@ impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
         other.blit_from(self, info);
         self
     }
+    /// Download texels from the current [`Self::read_buffer`] into host memory.
+    ///
+    /// `size` texels are read starting at `offset`, and packed into `data` in `format`. Each row
+    /// is padded to a multiple of four bytes (the default `GL_PACK_ALIGNMENT`).
+    /// `[0, 0]` is defined to be the lower-left corner.
+    ///
+    /// Static proof that no buffer is bound to [`PixelPack`] is required, as otherwise the
+    /// GL would interpret the pointer to `data` as an offset into that buffer.
+    /// See [`Self::read_pixels_to_buffer`] to read into a pixel pack buffer.
+    ///
+    /// GLES only guarantees support for `RGBA`+`U8` for normalized attachments, `RGBAInteger`+`I32`/`U32`
+    /// for integer attachments, `RGBA`+`F32` for float attachments, and one additional
+    /// implementation-chosen pairing.
+    ///
+    /// # Panics
+    /// * If `data` is too short to hold the requested region.
+    /// * In debug builds, if the region extends beyond the read buffer of a user-defined
+    ///   framebuffer. Texels outside of it would be left undefined.
+    /// * In debug builds, if the read buffer names an attachment with nothing attached.
+    #[doc(alias = "glReadPixels")]
+    pub fn read_pixels(
+        &self,
+        _pack: &crate::slot::buffer::Active<PixelPack, IsDefault>,
+        offset: [u32; 2],
+        size: [u32; 2],
+        format: Format,
+        mut data: ImageDataMut,
+    ) -> &Self {
+        let ty = data.data_type();
+        let (ptr, len) = data.as_mut_bytes();
+        assert!(
+            ty.image_len(format, [size[0], size[1], 1]) <= len,
+            "read_pixels destination too small"
+        );
+        #[cfg(debug_assertions)]
+        {
+            assert_read_buffer_attached("read_pixels");
+            assert_read_in_bounds(offset.map(i64::from), size);
+        }
+        unsafe {
+            gl::ReadPixels(
+                offset[0].try_into().unwrap(),
+                offset[1].try_into().unwrap(),
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+                format.as_gl(),
+                ty.as_gl(),
+                ptr,
+            );
+        }
+        self
+    }
+    /// Download texels from the current [`Self::read_buffer`] into the bound pixel pack buffer,
+    /// starting at `buffer_offset` bytes into the buffer.
+    ///
+    /// This does not wait for the transfer to complete - the pixel data can be fetched
+    /// asynchronously by [mapping](crate::slot::buffer::Active::map) the buffer at a later time.
+    ///
+    /// See [`Self::read_pixels`] for the meaning of the other parameters.
+    ///
+    /// `buffer_offset` must be a multiple of `ty.size_of()`, the pack buffer must not be mapped, and
+    /// the written range must not extend beyond the end of the buffer. These conditions are errors,
+    /// but are not UB.
+    ///
+    /// # Panics
+    /// In debug builds, as [`Self::read_pixels`].
+    #[doc(alias = "glReadPixels")]
+    pub fn read_pixels_to_buffer(
+        &self,
+        pack: &mut crate::slot::buffer::Active<PixelPack, NotDefault>,
+        offset: [u32; 2],
+        size: [u32; 2],
+        format: Format,
+        ty: DataType,
+        buffer_offset: usize,
+    ) -> &Self {
+        #[cfg(debug_assertions)]
+        {
+            // Check pack buffer bounds.
+            let len = pack.len();
+            assert!(
+                (buffer_offset + ty.image_len(format, [size[0], size[1], 1])) <= len,
+                "read_pixels_to_buffer() range out of bounds"
+            );
+            assert_read_buffer_attached("read_pixels_to_buffer");
+            assert_read_in_bounds(offset.map(i64::from), size);
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = pack;
+
+        unsafe {
+            gl::ReadPixels(
+                offset[0].try_into().unwrap(),
+                offset[1].try_into().unwrap(),
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+                format.as_gl(),
+                ty.as_gl(),
+                // Interpreted as a byte offset, as `pack` proves a pixel pack buffer is bound.
+                buffer_offset as _,
+            );
+        }
+        self
+    }
     /// Copy texels from the current [`Self::read_buffer`] to the given bound texture.
     ///
     /// Texels are taken from the read buffer starting at `source_offset`, and `size` texels
@@ -211,10 +874,14 @@ impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
     ///
     /// `[0, 0]` is defined to be the lower-left corner.
     ///
+    /// # Panics
+    /// In debug builds, if the read buffer names an attachment with nothing attached.
+    ///
     /// # Safety
     /// If the source range extends beyond the extent of the current `read_buffer`, the values
     /// transferred from those texels are undefined. This is *not* immediate UB, but it would
-    /// be UB for any read access to those values in the destination texture.
+    /// be UB for any read access to those values in the destination texture. In debug builds,
+    /// this is checked where the extent of the read buffer can be determined.
     #[doc(alias = "glCopyTexSubImage2D")]
     pub unsafe fn copy_subimage_to(
         &self,
@@ -226,6 +893,11 @@ impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
         destination_offset: [u32; 2],
         size: [u32; 2],
     ) -> &Self {
+        #[cfg(debug_assertions)]
+        {
+            assert_read_buffer_attached("copy_subimage_to");
+            assert_read_in_bounds(source_offset.map(i64::from), size);
+        }
         unsafe {
             gl::CopyTexSubImage2D(
                 crate::texture::D2::TARGET,
@@ -247,10 +919,14 @@ impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
     ///
     /// `[0, 0]` is defined to be the lower-left corner.
     ///
+    /// # Panics
+    /// In debug builds, if the read buffer names an attachment with nothing attached.
+    ///
     /// # Safety
     /// If the source range extends beyond the extent of the current `read_buffer`, the values
     /// transferred from those texels are undefined. This is *not* immediate UB, but it would
-    /// be UB for any read access to those values in the destination texture.
+    /// be UB for any read access to those values in the destination texture. In debug builds,
+    /// this is checked where the extent of the read buffer can be determined.
     #[doc(alias = "glCopyTexImage2D")]
     pub unsafe fn copy_image_to(
         &self,
@@ -263,6 +939,11 @@ impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
         source_offset: [i32; 2],
         size: [u32; 2],
     ) -> &Self {
+        #[cfg(debug_assertions)]
+        {
+            assert_read_buffer_attached("copy_image_to");
+            assert_read_in_bounds(source_offset.map(i64::from), size);
+        }
         unsafe {
             gl::CopyTexImage2D(
                 crate::texture::D2::TARGET,
@@ -280,18 +961,45 @@ impl<AnyDefaultness: Defaultness> Active<Read, AnyDefaultness, Complete> {
 }
 
 impl<AnyCompleteness> Active<Draw, NotDefault, AnyCompleteness> {
+    fn set_draw_buffers(&mut self, buffers: &[Buffer]) -> &mut Self {
+        assert!(is_all_unique(buffers));
+        // Cast safety: Fieldless repr(u32), can be safely reinterpreted as &[u32]
+        unsafe { gl::DrawBuffers(buffers.len().try_into().unwrap(), buffers.as_ptr().cast()) }
+        self
+    }
+}
+
+impl Active<Draw, NotDefault, Incomplete> {
     /// Direct fragment outputs into appropriate buffers.
     /// I.e., Fragment output 0 will go into the buffer defined by `buffers[0]`.
     /// If the slice is too short, remaining slots default to [`Buffer::None`]
     ///
+    /// Attachments may still be added after this, so whether the selected ones are populated is
+    /// checked on [completion](Slot::try_complete) instead.
+    ///
     /// # Panics
     /// Every element of `buffers` must be either none or a unique value.
     #[doc(alias = "glDrawBuffers")]
     pub fn draw_buffers(&mut self, buffers: &[Buffer]) -> &mut Self {
-        assert!(is_all_unique(buffers));
-        // Cast safety: Fieldless repr(u32), can be safely reinterpreted as &[u32]
-        unsafe { gl::DrawBuffers(buffers.len().try_into().unwrap(), buffers.as_ptr().cast()) }
-        self
+        self.set_draw_buffers(buffers)
+    }
+}
+
+impl Active<Draw, NotDefault, Complete> {
+    /// Direct fragment outputs into appropriate buffers.
+    /// I.e., Fragment output 0 will go into the buffer defined by `buffers[0]`.
+    /// If the slice is too short, remaining slots default to [`Buffer::None`]
+    ///
+    /// # Panics
+    /// * Every element of `buffers` must be either none or a unique value.
+    /// * In debug builds, if any element of `buffers` names an attachment with nothing attached.
+    #[doc(alias = "glDrawBuffers")]
+    pub fn draw_buffers(&mut self, buffers: &[Buffer]) -> &mut Self {
+        #[cfg(debug_assertions)]
+        for attachment in buffers.iter().filter_map(Buffer::attachment) {
+            assert_buffer_attached::<Draw>("draw_buffers", attachment);
+        }
+        self.set_draw_buffers(buffers)
     }
 }
 
@@ -311,8 +1019,239 @@ impl Active<Draw, IsDefault, Complete> {
     }
 }
 
+impl<T: Target, AnyCompleteness> Active<T, NotDefault, AnyCompleteness> {
+    /// Hint that the contents of `attachments` are no longer needed, and may become undefined.
+    ///
+    /// On tiled GPUs, this saves writing the contents back to memory at the end of a pass, e.g. for
+    /// a depth buffer only needed during rendering.
+    #[doc(alias = "glInvalidateFramebuffer")]
+    pub fn invalidate(&mut self, attachments: &[Attachment]) -> &mut Self {
+        // Cast safety: Fieldless repr(u32), can be safely reinterpreted as &[u32]
+        unsafe {
+            gl::InvalidateFramebuffer(
+                T::TARGET,
+                attachments.len().try_into().unwrap(),
+                attachments.as_ptr().cast(),
+            );
+        }
+        self
+    }
+    /// Like [`Self::invalidate`], limited to the rectangle of `size` texels starting at `min`.
+    ///
+    /// `min` is the lower-left.
+    #[doc(alias = "glInvalidateSubFramebuffer")]
+    pub fn invalidate_sub(
+        &mut self,
+        attachments: &[Attachment],
+        min: [u32; 2],
+        size: [u32; 2],
+    ) -> &mut Self {
+        // Cast safety: Fieldless repr(u32), can be safely reinterpreted as &[u32]
+        unsafe {
+            gl::InvalidateSubFramebuffer(
+                T::TARGET,
+                attachments.len().try_into().unwrap(),
+                attachments.as_ptr().cast(),
+                min[0].try_into().unwrap(),
+                min[1].try_into().unwrap(),
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+            );
+        }
+        self
+    }
+    /// Zero if the query is an error, e.g. [`Attachment::DepthStencil`] with differing images
+    /// bound to each aspect.
+    unsafe fn attachment_parameter(attachment: Attachment, pname: GLenum) -> GLenum {
+        let value = unsafe {
+            let mut value = 0;
+            gl::GetFramebufferAttachmentParameteriv(
+                T::TARGET,
+                attachment.as_gl(),
+                pname,
+                &mut value,
+            );
+            value
+        };
+        value as _
+    }
+    /// Query what kind of image, if any, is bound to `attachment`.
+    ///
+    /// [`Attachment::DepthStencil`] reads as [`AttachmentKind::None`] if differing images are bound
+    /// to the depth and stencil aspects.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetFramebufferAttachmentParameteriv")]
+    #[doc(alias = "GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE")]
+    #[must_use]
+    pub fn attachment_kind(&self, attachment: Attachment) -> AttachmentKind {
+        match unsafe {
+            Self::attachment_parameter(attachment, gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+        } {
+            gl::TEXTURE => AttachmentKind::Texture,
+            gl::RENDERBUFFER => AttachmentKind::Renderbuffer,
+            _ => AttachmentKind::None,
+        }
+    }
+    /// Query the number of depth bits of the image bound to `attachment`, or zero if there is none
+    /// or it has no depth aspect.
+    ///
+    /// This is not cached and invokes up to two `glGet`s.
+    #[doc(alias = "glGetFramebufferAttachmentParameteriv")]
+    #[doc(alias = "GL_FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE")]
+    #[must_use]
+    pub fn attachment_depth_bits(&self, attachment: Attachment) -> u32 {
+        if self.attachment_kind(attachment) == AttachmentKind::None {
+            return 0;
+        }
+        unsafe { Self::attachment_parameter(attachment, gl::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE) }
+    }
+    /// Query the number of stencil bits of the image bound to `attachment`, or zero if there is none
+    /// or it has no stencil aspect.
+    ///
+    /// This is not cached and invokes up to two `glGet`s.
+    #[doc(alias = "glGetFramebufferAttachmentParameteriv")]
+    #[doc(alias = "GL_FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE")]
+    #[must_use]
+    pub fn attachment_stencil_bits(&self, attachment: Attachment) -> u32 {
+        if self.attachment_kind(attachment) == AttachmentKind::None {
+            return 0;
+        }
+        unsafe { Self::attachment_parameter(attachment, gl::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE) }
+    }
+    /// Query how the components of the image bound to `attachment` are interpreted,
+    /// or `None` if nothing is bound.
+    ///
+    /// [`Attachment::DepthStencil`] cannot be queried, as the two aspects have differing
+    /// types - query [`Attachment::Depth`] and [`Attachment::Stencil`] instead.
+    ///
+    /// This is not cached and invokes up to two `glGet`s.
+    #[doc(alias = "glGetFramebufferAttachmentParameteriv")]
+    #[doc(alias = "GL_FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE")]
+    #[must_use]
+    pub fn attachment_component_type(&self, attachment: Attachment) -> Option<ComponentType> {
+        if self.attachment_kind(attachment) == AttachmentKind::None {
+            // Querying the type of nothing is an error.
+            return None;
+        }
+        match unsafe {
+            Self::attachment_parameter(attachment, gl::FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE)
+        } {
+            gl::FLOAT => Some(ComponentType::Float),
+            gl::INT => Some(ComponentType::Int),
+            gl::UNSIGNED_INT => Some(ComponentType::UnsignedInt),
+            gl::UNSIGNED_NORMALIZED => Some(ComponentType::UnsignedNormalized),
+            gl::SIGNED_NORMALIZED => Some(ComponentType::SignedNormalized),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Target> Active<T, IsDefault, Complete> {
+    /// Query the number of bits of `buffer` (one of `GL_DEPTH` or `GL_STENCIL`) of the default
+    /// framebuffer, or zero if it has no such buffer.
+    unsafe fn default_buffer_bits(buffer: GLenum, pname: GLenum) -> u32 {
+        let get = |pname| unsafe {
+            let mut value = 0;
+            gl::GetFramebufferAttachmentParameteriv(T::TARGET, buffer, pname, &mut value);
+            value
+        };
+        // Querying the size of a missing buffer is an error.
+        if get(gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE) as GLenum == gl::NONE {
+            return 0;
+        }
+        get(pname).try_into().unwrap()
+    }
+    /// Query the number of bits of the default framebuffer's depth buffer, or zero if it has none.
+    ///
+    /// The default framebuffer's buffers are fixed by the config chosen at context creation.
+    ///
+    /// This is not cached and invokes up to two `glGet`s.
+    #[doc(alias = "glGetFramebufferAttachmentParameteriv")]
+    #[doc(alias = "GL_FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE")]
+    #[must_use]
+    pub fn depth_bits(&self) -> u32 {
+        unsafe { Self::default_buffer_bits(gl::DEPTH, gl::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE) }
+    }
+    /// Query the number of bits of the default framebuffer's stencil buffer, or zero if it has none.
+    ///
+    /// The default framebuffer's buffers are fixed by the config chosen at context creation.
+    ///
+    /// This is not cached and invokes up to two `glGet`s.
+    #[doc(alias = "glGetFramebufferAttachmentParameteriv")]
+    #[doc(alias = "GL_FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE")]
+    #[must_use]
+    pub fn stencil_bits(&self) -> u32 {
+        unsafe { Self::default_buffer_bits(gl::STENCIL, gl::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE) }
+    }
+    /// Query whether the default framebuffer has a depth buffer. See [`Self::depth_bits`].
+    #[must_use]
+    pub fn has_depth(&self) -> bool {
+        self.depth_bits() != 0
+    }
+    /// Query whether the default framebuffer has a stencil buffer. See [`Self::stencil_bits`].
+    #[must_use]
+    pub fn has_stencil(&self) -> bool {
+        self.stencil_bits() != 0
+    }
+}
+
+impl Active<Read, NotDefault, Complete> {
+    /// Download floating point texels from the current [`Self::read_buffer`] into a new allocation,
+    /// in `RGBA` order.
+    ///
+    /// `size` texels are read starting at `offset`, row-by-row from the lower-left corner. Missing
+    /// channels read as `0.0` for color and `1.0` for alpha. GLES never clamps float values on read,
+    /// so HDR contents are returned as-is.
+    ///
+    /// This is [`Self::read_pixels`] with the one pairing of [`Format::RGBA`] and [`DataType::F32`]
+    /// that GLES guarantees for float attachments.
+    ///
+    /// # Panics
+    /// If the read buffer is not a [float](ComponentType::Float) color attachment.
+    #[cfg(feature = "alloc")]
+    #[doc(alias = "glReadPixels")]
+    pub fn read_pixels_f32(
+        &self,
+        pack: &crate::slot::buffer::Active<PixelPack, IsDefault>,
+        offset: [u32; 2],
+        size: [u32; 2],
+    ) -> alloc::vec::Vec<[f32; 4]> {
+        let read_buffer = unsafe {
+            let mut read_buffer = core::mem::MaybeUninit::uninit();
+            gl::GetIntegerv(gl::READ_BUFFER, read_buffer.as_mut_ptr());
+            read_buffer.assume_init()
+        };
+        let attachment = match read_buffer as GLenum {
+            gl::COLOR_ATTACHMENT0 => Attachment::Color0,
+            gl::COLOR_ATTACHMENT1 => Attachment::Color1,
+            gl::COLOR_ATTACHMENT2 => Attachment::Color2,
+            gl::COLOR_ATTACHMENT3 => Attachment::Color3,
+            _ => panic!("read_pixels_f32() with no color read buffer"),
+        };
+        assert_eq!(
+            self.attachment_component_type(attachment),
+            Some(ComponentType::Float),
+            "read_pixels_f32() from non-float attachment"
+        );
+
+        let [width, height] = size.map(|n| usize::try_from(n).unwrap());
+        let mut texels = alloc::vec![[0.0; 4]; width.checked_mul(height).unwrap()];
+        self.read_pixels(
+            pack,
+            offset,
+            size,
+            Format::RGBA,
+            ImageDataMut::F32(texels.as_flattened_mut()),
+        );
+        texels
+    }
+}
+
 impl<AnyCompleteness> Active<Read, NotDefault, AnyCompleteness> {
     /// Set the source for pixel read operations.
+    ///
+    /// In debug builds, reading from a `buffer` naming an attachment with nothing attached panics.
     #[doc(alias = "glReadBuffer")]
     pub fn read_buffer(&mut self, buffer: Buffer) -> &mut Self {
         unsafe { gl::ReadBuffer(buffer.as_gl()) }
@@ -338,6 +1277,20 @@ pub struct IncompleteError<'slot, Slot> {
     /// Returns ownership of the framebuffer.
     pub framebuffer: Incomplete,
     pub kind: IncompleteErrorKind,
+    /// Which attachments disagreed, if the failure was found by the sample count check of
+    /// [`Slot::try_complete_with`]. Always `None` when the GL reported the failure.
+    pub sample_mismatch: Option<SampleMismatch>,
+}
+
+/// Two attachments with differing sample counts, found before asking the GL for completeness.
+///
+/// Single-sampled attachments, including all textures, have a sample count of `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleMismatch {
+    /// The first attachment, whose sample count the others were compared against.
+    pub expected: (Attachment, u32),
+    /// The first attachment to disagree with `expected`.
+    pub actual: (Attachment, u32),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -349,7 +1302,8 @@ pub enum IncompleteErrorKind {
     // Undefined,
     /// One or more of the attachments are "framebuffer incomplete".
     Attachment = gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT,
-    /// The framebuffer has no attachments.
+    /// The framebuffer has no attachments, and no nonzero
+    /// [default dimensions](Active::default_dimensions).
     MissingAttachment = gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT,
     /// Either of:
     /// * Depth and Stencil attachments refer to different renderbuffers or textures.
@@ -383,6 +1337,8 @@ impl<T: Target> Slot<T> {
     /// Bind a user-defined framebuffer to this slot.
     #[doc(alias = "glBindFramebuffer")]
     pub fn bind(&mut self, framebuffer: &Incomplete) -> &mut Active<T, NotDefault, Incomplete> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(framebuffer);
         unsafe {
             gl::BindFramebuffer(T::TARGET, framebuffer.0.get());
         }
@@ -394,6 +1350,8 @@ impl<T: Target> Slot<T> {
         &mut self,
         framebuffer: &Complete,
     ) -> &mut Active<T, NotDefault, Complete> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(framebuffer);
         unsafe {
             gl::BindFramebuffer(T::TARGET, framebuffer.0.get());
         }
@@ -402,15 +1360,113 @@ impl<T: Target> Slot<T> {
     /// Check completeness of the given framebuffer, binding it in the process.
     ///
     /// On failure, the incomplete framebuffer is returned unchanged.
+    ///
+    /// If the framebuffer is already bound, [`Active::check_complete`] avoids rebinding it.
+    ///
+    /// # Panics
+    /// In debug builds, if completing for [`Draw`] and a draw buffer other than the first was
+    /// [selected](Active::draw_buffers) naming an attachment with nothing attached.
     // It is a limitation of my design that this requires a possibly redundant bind..
     #[doc(alias = "glCheckFramebufferStatus")]
+    #[allow(clippy::type_complexity)]
     pub fn try_complete(
         &mut self,
         framebuffer: Incomplete,
-    ) -> Result<(Complete, &mut Active<T, NotDefault, Complete>), IncompleteError<T>> {
+    ) -> Result<(Complete, &mut Active<T, NotDefault, Complete>), IncompleteError<'_, T>> {
         let active = self.bind(&framebuffer);
+        Self::check_bound(active, framebuffer)
+    }
+    /// Bind the framebuffer, replace the given attachment points, and check completeness.
+    ///
+    /// Attachments not named in `attachments` are left unchanged. The renderbuffer slot is
+    /// left bound to an unspecified renderbuffer.
+    ///
+    /// Before asking the GL, this checks that every attachment has the same sample count - the
+    /// most common failure when setting up a multisampled framebuffer. If they differ,
+    /// [`IncompleteErrorKind::Multisample`] is returned, with the offending attachments named in
+    /// [`IncompleteError::sample_mismatch`].
+    ///
+    /// On failure, the incomplete framebuffer is returned with the new attachments.
+    ///
+    /// # Panics
+    /// See [`Self::try_complete`].
+    ///
+    /// Usage:
+    /// ```no_run
+    /// use glhf::framebuffer::Attachment;
+    /// # let mut gl : glhf::GLHF = todo!();
+    /// # let [framebuffer] = gl.new.framebuffers();
+    /// # let [color, depth] = gl.new.render_buffers();
+    /// let (framebuffer, _) = gl
+    ///     .framebuffer
+    ///     .draw
+    ///     .try_complete_with(
+    ///         &mut gl.renderbuffer,
+    ///         framebuffer,
+    ///         &[
+    ///             (Attachment::Color0, (&color).into()),
+    ///             (Attachment::Depth, (&depth).into()),
+    ///         ],
+    ///     )
+    ///     .unwrap();
+    /// ```
+    #[doc(alias = "glCheckFramebufferStatus")]
+    #[doc(alias = "glFramebufferRenderbuffer")]
+    #[doc(alias = "glFramebufferTexture2D")]
+    #[allow(clippy::type_complexity)]
+    pub fn try_complete_with(
+        &mut self,
+        renderbuffer: &mut crate::slot::renderbuffer::Slot,
+        framebuffer: Incomplete,
+        attachments: &[(Attachment, AttachmentSource)],
+    ) -> Result<(Complete, &mut Active<T, NotDefault, Complete>), IncompleteError<'_, T>> {
+        let active = self.bind(&framebuffer);
+
+        let mut expected = None;
+        let mut sample_mismatch = None;
+        for &(attachment, source) in attachments {
+            let samples = match source {
+                AttachmentSource::Texture2D { texture, level } => {
+                    active.texture_2d(texture, attachment, level);
+                    0
+                }
+                AttachmentSource::Renderbuffer(source) => {
+                    active.renderbuffer(source, attachment);
+                    renderbuffer.bind(source).samples()
+                }
+            };
+            let expected = *expected.get_or_insert((attachment, samples));
+            if expected.1 != samples && sample_mismatch.is_none() {
+                sample_mismatch = Some(SampleMismatch {
+                    expected,
+                    actual: (attachment, samples),
+                });
+            }
+        }
+
+        if sample_mismatch.is_none() {
+            Self::check_bound(active, framebuffer)
+        } else {
+            Err(IncompleteError {
+                active,
+                kind: IncompleteErrorKind::Multisample,
+                framebuffer,
+                sample_mismatch,
+            })
+        }
+    }
+    /// Check completeness of `framebuffer`, which must be the framebuffer bound to `T`.
+    #[allow(clippy::type_complexity)]
+    fn check_bound(
+        active: &mut Active<T, NotDefault, Incomplete>,
+        framebuffer: Incomplete,
+    ) -> Result<(Complete, &mut Active<T, NotDefault, Complete>), IncompleteError<'_, T>> {
         let status = unsafe { gl::CheckFramebufferStatus(T::TARGET) };
         if status == gl::FRAMEBUFFER_COMPLETE {
+            #[cfg(debug_assertions)]
+            if T::TARGET == gl::DRAW_FRAMEBUFFER {
+                assert_selected_draw_buffers_attached();
+            }
             Ok((
                 // Safety - we just checked, dummy!
                 unsafe { framebuffer.into_complete_unchecked() },
@@ -421,6 +1477,7 @@ impl<T: Target> Slot<T> {
                 active,
                 kind: IncompleteErrorKind::from_gl(status),
                 framebuffer,
+                sample_mismatch: None,
             })
         }
     }
@@ -446,6 +1503,47 @@ impl<T: Target> Slot<T> {
     pub fn inherit_mut(&mut self) -> &mut Active<T, Unknown, Unknown> {
         super::zst_mut()
     }
+    /// Inherit the currently bound framebuffer, asserting its defaultness and completeness.
+    ///
+    /// This allows the full API to be used on a framebuffer bound by code outside of this crate,
+    /// such as a host application, without rebinding it.
+    ///
+    /// In debug builds, the claims are checked, at the cost of a `glGet` and possibly a
+    /// `glCheckFramebufferStatus`.
+    ///
+    /// # Safety
+    /// * If `Default` is [`IsDefault`], the default framebuffer must be bound to this slot.
+    ///   If [`NotDefault`], a user-defined framebuffer must be bound.
+    /// * If `Completeness` is [`Complete`], the bound framebuffer must be complete.
+    #[doc(alias = "GL_DRAW_FRAMEBUFFER_BINDING")]
+    #[doc(alias = "GL_READ_FRAMEBUFFER_BINDING")]
+    #[must_use]
+    pub unsafe fn inherit_as<Default: Defaultness, Completeness: 'static>(
+        &mut self,
+    ) -> &mut Active<T, Default, Completeness> {
+        #[cfg(debug_assertions)]
+        {
+            use core::any::TypeId;
+            let binding = unsafe {
+                let mut binding = core::mem::MaybeUninit::uninit();
+                gl::GetIntegerv(binding_pname::<T>(), binding.as_mut_ptr());
+                binding.assume_init()
+            };
+            if TypeId::of::<Default>() == TypeId::of::<IsDefault>() {
+                assert_eq!(binding, 0, "inherit_as() claimed the default framebuffer");
+            } else if TypeId::of::<Default>() == TypeId::of::<NotDefault>() {
+                assert_ne!(binding, 0, "inherit_as() claimed a user framebuffer");
+            }
+            if TypeId::of::<Completeness>() == TypeId::of::<Complete>() {
+                assert_eq!(
+                    unsafe { gl::CheckFramebufferStatus(T::TARGET) },
+                    gl::FRAMEBUFFER_COMPLETE,
+                    "inherit_as() claimed a complete framebuffer"
+                );
+            }
+        }
+        super::zst_mut()
+    }
 }
 
 pub struct Slots {
@@ -464,6 +1562,8 @@ impl Slots {
         &mut Active<Read, NotDefault, Incomplete>,
         &mut Active<Draw, NotDefault, Incomplete>,
     ) {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(framebuffer);
         unsafe {
             gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer.0.get());
         }
@@ -480,6 +1580,8 @@ impl Slots {
         &mut Active<Read, NotDefault, Complete>,
         &mut Active<Draw, NotDefault, Complete>,
     ) {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(framebuffer);
         unsafe {
             gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer.0.get());
         }
@@ -2,13 +2,15 @@
 use crate::{
     gl::{
         self,
-        types::{GLchar, GLenum, GLint, GLsizei, GLuint},
+        types::{GLchar, GLsizei, GLuint},
     },
     program::{self, CompiledShader, EmptyShader, LinkedProgram, Program, ProgramShaders, Type},
     slot::marker::{IsDefault, NotDefault, Unknown},
     NotSync, ThinGLObject,
 };
 #[cfg(feature = "alloc")]
+use gl::types::{GLenum, GLint};
+#[cfg(feature = "alloc")]
 unsafe fn info_log(
     name: GLuint,
     get_iv: unsafe fn(GLuint, GLenum, *mut GLint),
@@ -41,6 +43,28 @@ unsafe fn info_log(
     // Expect nul-terminated string from vec.
     alloc::ffi::CString::from_vec_with_nul(string_bytes).unwrap()
 }
+/// Write as much of the log as fits into `buf`, followed by a nul terminator.
+/// Returns the number of bytes written, excluding the terminator.
+unsafe fn info_log_into(
+    name: GLuint,
+    fetch_log: unsafe fn(GLuint, GLsizei, *mut GLsizei, *mut GLchar),
+    buf: &mut [u8],
+) -> usize {
+    // No room for even the nul terminator.
+    if buf.is_empty() {
+        return 0;
+    }
+    let mut length = 0;
+    fetch_log(
+        name,
+        // In param for max length, saturating on absurdly large buffers.
+        buf.len().try_into().unwrap_or(GLsizei::MAX),
+        // Out param for actual length
+        core::ptr::addr_of_mut!(length),
+        buf.as_mut_ptr().cast(),
+    );
+    length.try_into().unwrap()
+}
 #[cfg(feature = "alloc")]
 unsafe fn shader_log(shader: GLuint) -> alloc::ffi::CString {
     info_log(shader, gl::GetShaderiv, gl::GetShaderInfoLog)
@@ -59,6 +83,18 @@ pub struct CompileError<Ty: Type> {
     pub error: alloc::ffi::CString,
 }
 
+impl<Ty: Type> CompileError<Ty> {
+    /// Write as much of the GL-provided error log as fits into `buf`, followed by a nul terminator.
+    /// Returns the number of bytes written, excluding the terminator.
+    ///
+    /// This is available without the `alloc` feature, for use with a fixed scratch buffer.
+    /// This is not cached, and invokes a `glGet`.
+    #[doc(alias = "glGetShaderInfoLog")]
+    pub fn log_into(&self, buf: &mut [u8]) -> usize {
+        unsafe { info_log_into(self.shader.name().get(), gl::GetShaderInfoLog, buf) }
+    }
+}
+
 #[derive(Debug)]
 #[must_use = "dropping a gl handle leaks resources"]
 /// If the feature `alloc` is enabled, includes the GL-provided error log.
@@ -68,6 +104,38 @@ pub struct LinkError {
     pub error: alloc::ffi::CString,
 }
 
+impl LinkError {
+    /// Write as much of the GL-provided error log as fits into `buf`, followed by a nul terminator.
+    /// Returns the number of bytes written, excluding the terminator.
+    ///
+    /// This is available without the `alloc` feature, for use with a fixed scratch buffer.
+    /// This is not cached, and invokes a `glGet`.
+    #[doc(alias = "glGetProgramInfoLog")]
+    pub fn log_into(&self, buf: &mut [u8]) -> usize {
+        unsafe { info_log_into(self.program.name().get(), gl::GetProgramInfoLog, buf) }
+    }
+}
+
+/// If the feature `alloc` is enabled, includes the GL-provided error log.
+#[derive(Debug)]
+pub struct ValidationError<'program> {
+    pub program: &'program LinkedProgram,
+    #[cfg(feature = "alloc")]
+    pub error: alloc::ffi::CString,
+}
+
+impl ValidationError<'_> {
+    /// Write as much of the GL-provided error log as fits into `buf`, followed by a nul terminator.
+    /// Returns the number of bytes written, excluding the terminator.
+    ///
+    /// This is available without the `alloc` feature, for use with a fixed scratch buffer.
+    /// This is not cached, and invokes a `glGet`.
+    #[doc(alias = "glGetProgramInfoLog")]
+    pub fn log_into(&self, buf: &mut [u8]) -> usize {
+        unsafe { info_log_into(self.program.name().get(), gl::GetProgramInfoLog, buf) }
+    }
+}
+
 impl Active<NotDefault> {
     /// Starting at `base_location`, bind one (or an array) of uniform scalars or vectors.
     /// The value may only be an array if it was declared as an array within the shader.
@@ -169,6 +237,33 @@ impl Active<NotDefault> {
         }
         self
     }
+    /// Bind a single `bool` (with `N = 1`) or `bvecN` uniform.
+    ///
+    /// GLSL booleans have no uniform calls of their own, and are set through the integer calls
+    /// with `0` for `false` and `1` for `true`. To set an array of booleans, pass such `i32`s to
+    /// [`Self::uniform`].
+    ///
+    /// # Panics
+    /// At compile time, if `N` is not in `1..=4`.
+    #[doc(alias = "glUniform")]
+    #[doc(alias = "glUniform1i")]
+    #[doc(alias = "glUniform2i")]
+    #[doc(alias = "glUniform3i")]
+    #[doc(alias = "glUniform4i")]
+    pub fn uniform_bool<const N: usize>(&mut self, location: u32, value: [bool; N]) -> &mut Self {
+        const { assert!(N >= 1 && N <= 4, "bvec must have 1 to 4 components") };
+        let value = value.map(i32::from);
+        let location = location.try_into().unwrap();
+        unsafe {
+            match N {
+                1 => gl::Uniform1iv(location, 1, value.as_ptr()),
+                2 => gl::Uniform2iv(location, 1, value.as_ptr()),
+                3 => gl::Uniform3iv(location, 1, value.as_ptr()),
+                _ => gl::Uniform4iv(location, 1, value.as_ptr()),
+            }
+        }
+        self
+    }
     /// Starting at `base_location`, bind one (or an array) of uniform matrices.
     /// The value may only be an array if it was declared as an array within the shader.
     ///
@@ -275,6 +370,78 @@ impl Active<NotDefault> {
         }
         self
     }
+    /// Read back the current value of the uniform at `location` of the bound program into `out`.
+    ///
+    /// The entire value of the uniform is read, e.g. all 16 values of a `mat4`, or a single element
+    /// of an array. Values are converted to `T` if the uniform is of a different type.
+    ///
+    /// Where `glGetnUniform*v` is available (GLES 3.2 or `GL_KHR_robustness`), `out` need only be
+    /// as long as the uniform, and the GL generates `GL_INVALID_OPERATION` and leaves `out`
+    /// unchanged if it is too short. Otherwise the GL gives no way to find the type of a uniform
+    /// from its location, so `out` must have room for the largest type, a `mat4`, regardless of the
+    /// uniform's actual size. Elements beyond the uniform's size are left unchanged.
+    ///
+    /// This invokes a `glGet` to find the bound program.
+    ///
+    /// # Panics
+    /// If `glGetnUniform*v` is unavailable and `out` is shorter than 16 elements.
+    #[doc(alias = "glGetnUniformfv")]
+    #[doc(alias = "glGetnUniformiv")]
+    #[doc(alias = "glGetnUniformuiv")]
+    #[doc(alias = "glGetUniformfv")]
+    #[doc(alias = "glGetUniformiv")]
+    #[doc(alias = "glGetUniformuiv")]
+    pub fn get_uniform<T: program::uniform::Value>(&self, location: u32, out: &mut [T]) -> &Self {
+        use program::uniform::Ty;
+        /// The number of components of a `mat4`, the largest uniform type.
+        const MAX_COMPONENTS: usize = 16;
+
+        // The robust entry points load under their KHR and EXT aliases too.
+        let robust = gl::GetnUniformfv::is_loaded();
+        assert!(
+            robust || out.len() >= MAX_COMPONENTS,
+            "get_uniform output must hold {MAX_COMPONENTS} elements, got {}",
+            out.len()
+        );
+        let program = unsafe {
+            let mut program = core::mem::MaybeUninit::uninit();
+            gl::GetIntegerv(gl::CURRENT_PROGRAM, program.as_mut_ptr());
+            program.assume_init()
+        };
+        let location = location.try_into().unwrap();
+        let program = program as _;
+
+        if robust {
+            // Safety: the GL writes no more than `len` elements.
+            let len = out.len().try_into().unwrap_or(gl::types::GLsizei::MAX);
+            match T::TYPE {
+                Ty::F32 => unsafe {
+                    gl::GetnUniformfv(program, location, len, out.as_mut_ptr().cast());
+                },
+                Ty::I32 => unsafe {
+                    gl::GetnUniformiv(program, location, len, out.as_mut_ptr().cast());
+                },
+                Ty::U32 => unsafe {
+                    gl::GetnUniformuiv(program, location, len, out.as_mut_ptr().cast());
+                },
+            }
+        } else {
+            // Safety: no uniform has more than `MAX_COMPONENTS` components, just checked there's
+            // room.
+            match T::TYPE {
+                Ty::F32 => unsafe {
+                    gl::GetUniformfv(program, location, out.as_mut_ptr().cast());
+                },
+                Ty::I32 => unsafe {
+                    gl::GetUniformiv(program, location, out.as_mut_ptr().cast());
+                },
+                Ty::U32 => unsafe {
+                    gl::GetUniformuiv(program, location, out.as_mut_ptr().cast());
+                },
+            }
+        }
+        self
+    }
 }
 
 /// Entry points for working with `glUse`d programs.
@@ -284,6 +451,8 @@ impl Slot {
     /// `glUse` a linked program.
     #[doc(alias = "glUseProgram")]
     pub fn bind(&mut self, program: &LinkedProgram) -> &mut Active<NotDefault> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(program);
         unsafe {
             gl::UseProgram(program.name().get());
         }
@@ -298,6 +467,10 @@ impl Slot {
         super::zst_mut()
     }
     /// Set the GLSL ES source code of a shader, then attempt to compile it.
+    ///
+    /// # Panics
+    /// In debug builds, if the compiler was [released](crate::hint::Hint::release_compiler)
+    /// and not since [reacquired](crate::hint::Hint::reacquire_compiler).
     // Is there a usecase for allowing each step of this process manually...?
     #[doc(alias = "glShaderSource")]
     #[doc(alias = "glCompileShader")]
@@ -306,12 +479,68 @@ impl Slot {
         shader: EmptyShader<Ty>,
         source: &str,
     ) -> Result<CompiledShader<Ty>, CompileError<Ty>> {
-        let sources = [source.as_ptr().cast::<gl::types::GLchar>()];
-        let lengths = [source.len().try_into().unwrap()];
+        self.compile_sources(shader, &[source])
+    }
+    /// Set the GLSL ES source code of a shader from the concatenation of several strings, then
+    /// attempt to compile it.
+    ///
+    /// This allows composing a shader from e.g. a [prelude](program::prelude), a block of
+    /// `#define`s, and a body, without concatenating them on the host. No separators are
+    /// inserted, so each string should end in a newline.
+    ///
+    /// # Panics
+    /// * See [`Self::compile`].
+    /// * Without the `alloc` feature, if there are more than 16 `sources`.
+    #[doc(alias = "glShaderSource")]
+    #[doc(alias = "glCompileShader")]
+    pub fn compile_sources<Ty: Type>(
+        &self,
+        shader: EmptyShader<Ty>,
+        sources: &[&str],
+    ) -> Result<CompiledShader<Ty>, CompileError<Ty>> {
+        #[cfg(debug_assertions)]
+        assert!(
+            !crate::hint::COMPILER_RELEASED.load(core::sync::atomic::Ordering::Relaxed),
+            "shader compiled after `Hint::release_compiler`, use `Hint::reacquire_compiler` if this is intended"
+        );
+
+        // The GL wants arrays of pointers and lengths. A few sources fit on the stack.
+        const INLINE: usize = 16;
+        let (mut inline_pointers, mut inline_lengths) = ([core::ptr::null(); INLINE], [0; INLINE]);
+        #[cfg(feature = "alloc")]
+        let (mut heap_pointers, mut heap_lengths);
+        let (pointers, lengths): (&mut [*const gl::types::GLchar], &mut [gl::types::GLint]) =
+            if sources.len() <= INLINE {
+                (
+                    &mut inline_pointers[..sources.len()],
+                    &mut inline_lengths[..sources.len()],
+                )
+            } else {
+                #[cfg(feature = "alloc")]
+                {
+                    heap_pointers = alloc::vec![core::ptr::null(); sources.len()];
+                    heap_lengths = alloc::vec![0; sources.len()];
+                    (&mut heap_pointers[..], &mut heap_lengths[..])
+                }
+                #[cfg(not(feature = "alloc"))]
+                panic!(
+                    "compile_sources() of more than {INLINE} sources requires the `alloc` feature"
+                )
+            };
+        for ((pointer, length), source) in pointers.iter_mut().zip(lengths.iter_mut()).zip(sources)
+        {
+            *pointer = source.as_ptr().cast();
+            *length = source.len().try_into().unwrap();
+        }
 
         let success = unsafe {
             // Source *may* have nul-bytes, as they are UTF8 - I couldn't find any verbage that says this *isn't* allowed ;3
-            gl::ShaderSource(shader.name().get(), 1, sources.as_ptr(), lengths.as_ptr());
+            gl::ShaderSource(
+                shader.name().get(),
+                sources.len().try_into().unwrap(),
+                pointers.as_ptr(),
+                lengths.as_ptr(),
+            );
             gl::CompileShader(shader.name().get());
 
             let mut was_successful = gl::FALSE.into();
@@ -341,19 +570,187 @@ impl Slot {
         }
     }
     /// Link together several compiled shaders into a [`LinkedProgram`]
-    // Is there a usecase for allowing each step of this process manually...?
+    ///
+    /// The shaders are attached for the duration of the link, and detached afterwards.
+    /// See [`Self::attach`] and [`Self::relink`] for doing each step manually.
     #[doc(alias = "glLinkProgram")]
     #[doc(alias = "glAttachShader")]
+    #[doc(alias = "glDetachShader")]
     pub fn link(
         &self,
         program: Program,
         shaders: ProgramShaders,
     ) -> Result<LinkedProgram, LinkError> {
-        let ProgramShaders::Graphics { vertex, fragment } = shaders;
-        let success = unsafe {
-            gl::AttachShader(program.name().get(), vertex.name().get());
-            gl::AttachShader(program.name().get(), fragment.name().get());
+        // Names of every present stage, in pipeline order.
+        let stages = unsafe {
+            match shaders {
+                ProgramShaders::Graphics {
+                    vertex,
+                    geometry,
+                    fragment,
+                } => [
+                    Some(vertex.name()),
+                    None,
+                    None,
+                    geometry.map(|geometry| geometry.name()),
+                    Some(fragment.name()),
+                ],
+                ProgramShaders::Tessellated {
+                    vertex,
+                    tess_control,
+                    tess_evaluation,
+                    geometry,
+                    fragment,
+                } => [
+                    Some(vertex.name()),
+                    Some(tess_control.name()),
+                    Some(tess_evaluation.name()),
+                    geometry.map(|geometry| geometry.name()),
+                    Some(fragment.name()),
+                ],
+            }
+        };
+        // The name survives linking, regardless of the outcome.
+        let name = unsafe { program.name() }.get();
+        for shader in stages.iter().flatten() {
+            unsafe {
+                gl::AttachShader(name, shader.get());
+            }
+        }
+        let result = self.relink(program);
+        for shader in stages.iter().flatten() {
+            unsafe {
+                gl::DetachShader(name, shader.get());
+            }
+        }
+        result
+    }
+    /// Attach a shader to a program, to be included the next time it is [linked](Self::relink).
+    ///
+    /// It is an error to attach more than one shader of a given [`Type`], or the same shader twice.
+    #[doc(alias = "glAttachShader")]
+    pub fn attach<Ty: Type>(&self, program: &Program, shader: &CompiledShader<Ty>) -> &Self {
+        unsafe {
+            gl::AttachShader(program.name().get(), shader.name().get());
+        }
+        self
+    }
+    /// Detach a shader previously [attached](Self::attach) to a program. This does not affect
+    /// the program if it is already linked.
+    ///
+    /// It is an error to detach a shader which is not attached.
+    #[doc(alias = "glDetachShader")]
+    pub fn detach<Ty: Type>(&self, program: &Program, shader: &CompiledShader<Ty>) -> &Self {
+        unsafe {
+            gl::DetachShader(program.name().get(), shader.name().get());
+        }
+        self
+    }
+    /// Query the names of the shaders currently [attached](Self::attach) to a program, in no
+    /// particular order.
+    ///
+    /// [`link`](Self::link) detaches its shaders immediately, so this is only useful for programs
+    /// assembled manually.
+    #[doc(alias = "glGetAttachedShaders")]
+    #[doc(alias = "GL_ATTACHED_SHADERS")]
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn attached_shaders<P: program::AnyProgram>(&self, program: &P) -> alloc::vec::Vec<GLuint> {
+        let program = unsafe { program.name() }.get();
+        let count = unsafe {
+            let mut count = core::mem::MaybeUninit::uninit();
+            gl::GetProgramiv(program, gl::ATTACHED_SHADERS, count.as_mut_ptr());
+            count.assume_init()
+        };
+        let mut shaders = alloc::vec![0; count.try_into().unwrap()];
+        let mut written = 0;
+        unsafe {
+            gl::GetAttachedShaders(program, count, &mut written, shaders.as_mut_ptr());
+        }
+        shaders.truncate(written.try_into().unwrap());
+        shaders
+    }
+    /// Query the placement of every active member of the uniform block at `block_index`, sorted by
+    /// offset.
+    ///
+    /// This allows packing host data to match a block without hardcoding its layout.
+    ///
+    /// A `block_index` of `GL_ACTIVE_UNIFORM_BLOCKS` or greater has no members.
+    #[doc(alias = "glGetActiveUniformsiv")]
+    #[doc(alias = "glGetActiveUniformBlockiv")]
+    #[doc(alias = "GL_UNIFORM_OFFSET")]
+    #[doc(alias = "GL_UNIFORM_ARRAY_STRIDE")]
+    #[doc(alias = "GL_UNIFORM_MATRIX_STRIDE")]
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn uniform_block_layout(
+        &self,
+        program: &LinkedProgram,
+        block_index: u32,
+    ) -> alloc::vec::Vec<program::UniformMember> {
+        let program = unsafe { program.name() }.get();
+        // Left at zero if `block_index` is out of range.
+        let count = unsafe {
+            let mut count = 0;
+            gl::GetActiveUniformBlockiv(
+                program,
+                block_index,
+                gl::UNIFORM_BLOCK_ACTIVE_UNIFORMS,
+                &mut count,
+            );
+            count
+        };
+        let count: usize = count.try_into().unwrap();
+        if count == 0 {
+            return alloc::vec::Vec::new();
+        }
+
+        let mut indices = alloc::vec![0 as GLint; count];
+        unsafe {
+            gl::GetActiveUniformBlockiv(
+                program,
+                block_index,
+                gl::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+                indices.as_mut_ptr(),
+            );
+        }
+        // Fetch one property of every member at once.
+        let get = |pname: GLenum| {
+            let mut values = alloc::vec![0 as GLint; count];
+            unsafe {
+                gl::GetActiveUniformsiv(
+                    program,
+                    count.try_into().unwrap(),
+                    // Cast: indices are never negative.
+                    indices.as_ptr().cast(),
+                    pname,
+                    values.as_mut_ptr(),
+                );
+            }
+            values
+        };
+        let offsets = get(gl::UNIFORM_OFFSET);
+        let array_strides = get(gl::UNIFORM_ARRAY_STRIDE);
+        let matrix_strides = get(gl::UNIFORM_MATRIX_STRIDE);
 
+        let mut members: alloc::vec::Vec<_> = (0..count)
+            .map(|i| program::UniformMember {
+                index: indices[i].try_into().unwrap(),
+                offset: offsets[i].try_into().unwrap(),
+                array_stride: array_strides[i].try_into().unwrap(),
+                matrix_stride: matrix_strides[i].try_into().unwrap(),
+            })
+            .collect();
+        members.sort_unstable_by_key(|member| member.offset);
+        members
+    }
+    /// Link together whichever shaders are currently [attached](Self::attach) to the program.
+    ///
+    /// To relink a [`LinkedProgram`] with a new combination of shaders, first convert it
+    /// with [`Into::into`].
+    #[doc(alias = "glLinkProgram")]
+    pub fn relink(&self, program: Program) -> Result<LinkedProgram, LinkError> {
+        let success = unsafe {
             gl::LinkProgram(program.name().get());
 
             let mut was_successful = gl::FALSE.into();
@@ -363,9 +760,6 @@ impl Slot {
                 core::ptr::addr_of_mut!(was_successful),
             );
 
-            gl::DetachShader(program.name().get(), vertex.name().get());
-            gl::DetachShader(program.name().get(), fragment.name().get());
-
             was_successful == gl::TRUE.into()
         };
 
@@ -386,6 +780,40 @@ impl Slot {
             }
         }
     }
+    /// Check whether the program could execute given the current GL state, such as a sampler
+    /// uniform of one type referring to a texture unit which has a different type bound.
+    ///
+    /// This is a diagnostic for when a draw silently produces nothing, and may be slow - it is
+    /// not needed for correct operation. The program need not be bound.
+    #[doc(alias = "glValidateProgram")]
+    #[doc(alias = "GL_VALIDATE_STATUS")]
+    pub fn validate<'program>(
+        &self,
+        program: &'program LinkedProgram,
+    ) -> Result<(), ValidationError<'program>> {
+        let success = unsafe {
+            gl::ValidateProgram(program.name().get());
+
+            let mut was_successful = gl::FALSE.into();
+            gl::GetProgramiv(
+                program.name().get(),
+                gl::VALIDATE_STATUS,
+                core::ptr::addr_of_mut!(was_successful),
+            );
+
+            was_successful == gl::TRUE.into()
+        };
+
+        if success {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                #[cfg(feature = "alloc")]
+                error: unsafe { program_log(program.name().get()) },
+                program,
+            })
+        }
+    }
     /// Inherit the currently bound program - this may be no program at all.
     ///
     /// Most functionality is limited when the status of the program (`Empty` or `NotEmpty`) is not known.
@@ -409,6 +837,8 @@ impl Slot {
     // does not invalidate outstanding `Active` markers.
     #[doc(alias = "glDeleteProgram")]
     pub fn delete(&self, program: Program) {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::untrack::<Program>(&[unsafe { program.name() }.get()]);
         unsafe { gl::DeleteProgram(program.into_name().get()) }
     }
     /// Delete a shader. If the shader is currently attached to any program, it remains so
@@ -417,6 +847,8 @@ impl Slot {
     /// To delete a [`CompiledShader`], use [`Into::into`].
     #[doc(alias = "glDeleteShader")]
     pub fn delete_shader<Ty: Type>(&self, shader: EmptyShader<Ty>) {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::untrack::<EmptyShader<Ty>>(&[unsafe { shader.name() }.get()]);
         unsafe { gl::DeleteShader(shader.into_name().get()) }
     }
 }
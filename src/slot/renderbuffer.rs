@@ -57,6 +57,24 @@ impl Active<NotDefault> {
         }
         self
     }
+    /// Get the number of samples of the renderbuffer's storage. Zero if it is not multisampled.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetRenderbufferParameteriv")]
+    #[doc(alias = "GL_RENDERBUFFER_SAMPLES")]
+    #[must_use]
+    pub fn samples(&self) -> u32 {
+        let samples = unsafe {
+            let mut samples = core::mem::MaybeUninit::uninit();
+            gl::GetRenderbufferParameteriv(
+                Renderbuffer::TARGET,
+                gl::RENDERBUFFER_SAMPLES,
+                samples.as_mut_ptr(),
+            );
+            samples.assume_init()
+        };
+        samples.try_into().unwrap()
+    }
 }
 
 /// Slots for binding renderbuffers. Corresponds to texture `glRenderbuffer*` operations.
@@ -65,6 +83,8 @@ impl Slot {
     /// Bind a rendebufferbuffer to this slot.
     #[doc(alias = "glBindRenderbuffer")]
     pub fn bind(&mut self, buffer: &Renderbuffer) -> &mut Active<NotDefault> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(buffer);
         unsafe {
             gl::BindRenderbuffer(Renderbuffer::TARGET, buffer.name().get());
         }
@@ -1,11 +1,17 @@
-//! Binding and manipulating `Texture{2D, 2DArray, 3D, Cube}`.
+//! Binding and manipulating `Texture{2D, 2DArray, 3D, Cube, 2DMultisample}`.
 
 use crate::{
     gl,
+    slot::{
+        buffer::{self, PixelPack, PixelUnpack},
+        framebuffer,
+        marker::{IsDefault, NotDefault},
+    },
     state::CompareFunc,
     texture::{
-        self, Cube, D2Array, Dimensionality, Filter, InternalFormat, Stateless, Swizzle, Texture,
-        D2, D3,
+        self, Cube, D2Array, D2Multisample, DataType, Dimensionality, Filter, Filterable, Format,
+        ImageData, ImageDataMut, InternalFormat, Stateless, Swizzle, Texture, TextureChannel,
+        TransferError, D2, D3,
     },
     GLEnum, GLenum, NonZero, NotSync,
 };
@@ -34,6 +40,33 @@ impl<Dim: Dimensionality> Active<Dim> {
         }
         self
     }
+    /// Set the swizzle of a single `channel`, leaving the others unchanged.
+    ///
+    /// For example, `swizzle_channel(TextureChannel::Green, Swizzle::Red)` and likewise for
+    /// `Blue` shows a single-channel texture as grayscale.
+    #[doc(alias = "glTexParameter")]
+    #[doc(alias = "glTexParameteri")]
+    #[doc(alias = "GL_TEXTURE_SWIZZLE_R")]
+    #[doc(alias = "GL_TEXTURE_SWIZZLE_G")]
+    #[doc(alias = "GL_TEXTURE_SWIZZLE_B")]
+    #[doc(alias = "GL_TEXTURE_SWIZZLE_A")]
+    pub fn swizzle_channel(&mut self, channel: TextureChannel, source: Swizzle) -> &mut Self {
+        unsafe {
+            Self::tex_parameter_enum(channel.as_gl(), source.as_gl());
+        }
+        self
+    }
+}
+
+/// Sampler state, which is meaningless for multisample textures as they are only ever fetched
+/// texel-by-texel.
+impl<Dim: Filterable> Active<Dim> {
+    /// Set the filter used when the texture is minified, and optionally between mip levels.
+    ///
+    /// # Panics
+    /// In debug builds, if either filter is [`Filter::Linear`] and the texture has an
+    /// [integer](InternalFormat::is_integer) format, which would leave the texture incomplete.
+    /// The format can only be checked on GLES 3.1 and once storage is defined.
     #[doc(alias = "glTexParameter")]
     #[doc(alias = "glTexParameteri")]
     #[doc(alias = "GL_TEXTURE_MIN_FILTER")]
@@ -46,11 +79,19 @@ impl<Dim: Dimensionality> Active<Dim> {
             (Filter::Linear, Some(Filter::Nearest)) => gl::LINEAR_MIPMAP_NEAREST,
             (Filter::Linear, Some(Filter::Linear)) => gl::LINEAR_MIPMAP_LINEAR,
         };
+        #[cfg(debug_assertions)]
+        if matches!(texel, Filter::Linear) || matches!(mip, Some(Filter::Linear)) {
+            assert_not_integer::<Dim>("min_filter");
+        }
         unsafe {
             Self::tex_parameter_enum(gl::TEXTURE_MIN_FILTER, filter);
         }
         self
     }
+    /// Set the filter used when the texture is magnified.
+    ///
+    /// # Panics
+    /// In debug builds, as in [`Self::min_filter`].
     #[doc(alias = "glTexParameter")]
     #[doc(alias = "glTexParameteri")]
     #[doc(alias = "GL_TEXTURE_MAG_FILTER")]
@@ -59,6 +100,10 @@ impl<Dim: Dimensionality> Active<Dim> {
             Filter::Nearest => gl::NEAREST,
             Filter::Linear => gl::LINEAR,
         };
+        #[cfg(debug_assertions)]
+        if matches!(texel, Filter::Linear) {
+            assert_not_integer::<Dim>("mag_filter");
+        }
         unsafe {
             Self::tex_parameter_enum(gl::TEXTURE_MAG_FILTER, filter);
         }
@@ -96,6 +141,84 @@ impl<Dim: Dimensionality> Active<Dim> {
         }
         self
     }
+    /// Shorthand for [`Filter::Linear`] [`Self::min_filter`] and [`Self::mag_filter`], without
+    /// mipmapping.
+    #[doc(alias = "GL_TEXTURE_MIN_FILTER")]
+    #[doc(alias = "GL_TEXTURE_MAG_FILTER")]
+    pub fn linear(&mut self) -> &mut Self {
+        self.min_filter(Filter::Linear, None)
+            .mag_filter(Filter::Linear)
+    }
+    /// Shorthand for [`Filter::Nearest`] [`Self::min_filter`] and [`Self::mag_filter`], without
+    /// mipmapping.
+    #[doc(alias = "GL_TEXTURE_MIN_FILTER")]
+    #[doc(alias = "GL_TEXTURE_MAG_FILTER")]
+    pub fn nearest(&mut self) -> &mut Self {
+        self.min_filter(Filter::Nearest, None)
+            .mag_filter(Filter::Nearest)
+    }
+    /// Shorthand for [`Self::wrap`] with [`Wrap::ClampToEdge`](texture::Wrap::ClampToEdge) in
+    /// every dimension.
+    pub fn clamp(&mut self) -> &mut Self {
+        self.wrap([texture::Wrap::ClampToEdge; 3])
+    }
+    /// Shorthand for [`Self::wrap`] with [`Wrap::Repeat`](texture::Wrap::Repeat) in every dimension.
+    pub fn repeat(&mut self) -> &mut Self {
+        self.wrap([texture::Wrap::Repeat; 3])
+    }
+    /// Clamps sampler level-of-detail calculations to the given range.
+    ///
+    /// The range may extend beyond the number of levels of `self`, it is silently clamped
+    /// during texture lookup.
+    #[doc(alias = "glTexParameter")]
+    #[doc(alias = "glTexParameterf")]
+    #[doc(alias = "TEXTURE_MIN_LOD")]
+    #[doc(alias = "TEXTURE_MAX_LOD")]
+    pub fn lod_range(&mut self, range: core::ops::RangeInclusive<f32>) -> &mut Self {
+        // would be nice if range was impl RangeBounds, but next_up/down isn't stable yet :V
+
+        unsafe {
+            gl::TexParameterf(Dim::TARGET, gl::TEXTURE_MIN_LOD, *range.start());
+            gl::TexParameterf(Dim::TARGET, gl::TEXTURE_MAX_LOD, *range.end());
+        }
+        self
+    }
+    /// Fill every mip level after the base level by successively downsampling it.
+    ///
+    /// The quality of the result is affected by [`Hint::generate_mipmap`](crate::hint::Hint::generate_mipmap).
+    #[doc(alias = "glGenerateMipmap")]
+    pub fn generate_mipmap(&mut self) -> &mut Self {
+        unsafe {
+            gl::GenerateMipmap(Dim::TARGET);
+        }
+        self
+    }
+    /// [`Self::generate_mipmap`] with the given quality hint, restoring the previous hint
+    /// afterwards such that it does not affect unrelated mipmap generation.
+    ///
+    /// This invokes a `glGet`.
+    #[doc(alias = "glGenerateMipmap")]
+    #[doc(alias = "GL_GENERATE_MIPMAP_HINT")]
+    pub fn generate_mipmap_with_quality(
+        &mut self,
+        hint: &crate::hint::Hint,
+        quality: crate::hint::Mode,
+    ) -> &mut Self {
+        let previous = unsafe {
+            let mut previous = core::mem::MaybeUninit::uninit();
+            gl::GetIntegerv(gl::GENERATE_MIPMAP_HINT, previous.as_mut_ptr());
+            previous.assume_init()
+        };
+        hint.generate_mipmap(quality);
+        self.generate_mipmap();
+        unsafe {
+            gl::Hint(gl::GENERATE_MIPMAP_HINT, previous.try_into().unwrap());
+        }
+        self
+    }
+}
+
+impl<Dim: Dimensionality> Active<Dim> {
     /// Hints to the GL the continuous range of mipmap levels that have defined contents.
     ///
     /// The range may extend beyond the number of levels of `self`, it is silently clamped
@@ -125,23 +248,6 @@ impl<Dim: Dimensionality> Active<Dim> {
         }
         self
     }
-    /// Clamps sampler level-of-detail calculations to the given range.
-    ///
-    /// The range may extend beyond the number of levels of `self`, it is silently clamped
-    /// during texture lookup.
-    #[doc(alias = "glTexParameter")]
-    #[doc(alias = "glTexParameterf")]
-    #[doc(alias = "TEXTURE_MIN_LOD")]
-    #[doc(alias = "TEXTURE_MAX_LOD")]
-    pub fn lod_range(&mut self, range: core::ops::RangeInclusive<f32>) -> &mut Self {
-        // would be nice if range was impl RangeBounds, but next_up/down isn't stable yet :V
-
-        unsafe {
-            gl::TexParameterf(Dim::TARGET, gl::TEXTURE_MIN_LOD, *range.start());
-            gl::TexParameterf(Dim::TARGET, gl::TEXTURE_MAX_LOD, *range.end());
-        }
-        self
-    }
     /// Set whether the Depth or the Stencil component is returned when sampling a combined
     /// depth-stencil texture.
     #[doc(alias = "glTexParameter")]
@@ -175,16 +281,274 @@ impl Active<D2> {
         };
         self
     }
+    /// Overwrite a region of the mip given by `level` with texels from host memory.
+    ///
+    /// `size` texels are written starting at `offset`, read from `data` in the layout described by
+    /// `format`. Each row of `data` is padded to a multiple of four bytes (the default `GL_UNPACK_ALIGNMENT`).
+    /// `[0, 0]` is defined to be the lower-left corner.
+    ///
+    /// Static proof that no buffer is bound to [`PixelUnpack`] is required, as otherwise the
+    /// GL would interpret the pointer to `data` as an offset into that buffer.
+    /// See [`Self::sub_image_from_buffer`] to upload from a pixel unpack buffer.
+    ///
+    /// # Panics
+    /// If `data` is too short to hold the described region, see [`ImageData::validate_for`].
+    ///
+    /// In debug builds only, if `format` and the type of `data` do not pair with the internal
+    /// format of the level, see [`InternalFormat::validate_transfer`]. This check requires GLES
+    /// 3.1, and is skipped otherwise. See [`Self::try_sub_image`] to check in all builds.
+    #[doc(alias = "glTexSubImage2D")]
+    pub fn sub_image(
+        &mut self,
+        unpack: &buffer::Active<PixelUnpack, IsDefault>,
+        level: u32,
+        offset: [u32; 2],
+        size: [u32; 2],
+        format: Format,
+        data: ImageData,
+    ) -> &mut Self {
+        data.validate_for(format, size[0], size[1], 1).unwrap();
+        #[cfg(debug_assertions)]
+        assert_transfer_pairs(level, format, data.data_type());
+        self.sub_image_unchecked(unpack, level, offset, size, format, data)
+    }
+    /// [`Self::sub_image`], returning an error instead of panicking if `data` is too short or
+    /// does not pair with the internal format of the level.
+    ///
+    /// The format check invokes `glGet`s, and requires GLES 3.1 - it is skipped otherwise.
+    ///
+    /// # Errors
+    /// * [`TransferError::Size`] if `data` is too short, see [`ImageData::validate_for`].
+    /// * [`TransferError::Format`] if `format` and the type of `data` do not pair with the
+    ///   internal format of the level, see [`InternalFormat::validate_transfer`].
+    #[doc(alias = "glTexSubImage2D")]
+    pub fn try_sub_image(
+        &mut self,
+        unpack: &buffer::Active<PixelUnpack, IsDefault>,
+        level: u32,
+        offset: [u32; 2],
+        size: [u32; 2],
+        format: Format,
+        data: ImageData,
+    ) -> Result<&mut Self, TransferError> {
+        data.validate_for(format, size[0], size[1], 1)?;
+        check_transfer_pairs(level, format, data.data_type())?;
+        Ok(self.sub_image_unchecked(unpack, level, offset, size, format, data))
+    }
+    fn sub_image_unchecked(
+        &mut self,
+        _unpack: &buffer::Active<PixelUnpack, IsDefault>,
+        level: u32,
+        offset: [u32; 2],
+        size: [u32; 2],
+        format: Format,
+        data: ImageData,
+    ) -> &mut Self {
+        let ty = data.data_type();
+        let (ptr, _) = data.as_bytes();
+        unsafe {
+            gl::TexSubImage2D(
+                D2::TARGET,
+                level.try_into().unwrap(),
+                offset[0].try_into().unwrap(),
+                offset[1].try_into().unwrap(),
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+                format.as_gl(),
+                ty.as_gl(),
+                ptr,
+            );
+        }
+        self
+    }
+    /// Overwrite a region of the mip given by `level` with texels from the bound pixel unpack buffer,
+    /// starting at `buffer_offset` bytes into the buffer.
+    ///
+    /// The transfer happens asynchronously, allowing the host to continue while the GL copies data.
+    /// Cycling between several unpack buffers allows streaming texture uploads without stalling.
+    ///
+    /// See [`Self::sub_image`] for the meaning of the other parameters.
+    ///
+    /// `buffer_offset` must be a multiple of `ty.size_of()`, the unpack buffer must not be mapped, and
+    /// the read range must not extend beyond the end of the buffer. These conditions are errors,
+    /// but are not UB.
+    ///
+    /// # Panics
+    /// In debug builds, if `format` and `ty` do not pair with the internal format of the level,
+    /// as in [`Self::sub_image`].
+    #[doc(alias = "glTexSubImage2D")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sub_image_from_buffer(
+        &mut self,
+        unpack: &buffer::Active<PixelUnpack, NotDefault>,
+        level: u32,
+        offset: [u32; 2],
+        size: [u32; 2],
+        format: Format,
+        ty: DataType,
+        buffer_offset: usize,
+    ) -> &mut Self {
+        #[cfg(debug_assertions)]
+        {
+            // Check unpack buffer bounds.
+            let len = unpack.len();
+            assert!(
+                (buffer_offset + ty.image_len(format, [size[0], size[1], 1])) <= len,
+                "sub_image_from_buffer() range out of bounds"
+            );
+            assert_transfer_pairs(level, format, ty);
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = unpack;
+
+        unsafe {
+            gl::TexSubImage2D(
+                D2::TARGET,
+                level.try_into().unwrap(),
+                offset[0].try_into().unwrap(),
+                offset[1].try_into().unwrap(),
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+                format.as_gl(),
+                ty.as_gl(),
+                // Interpreted as a byte offset, as `unpack` proves a pixel unpack buffer is bound.
+                buffer_offset as _,
+            );
+        }
+        self
+    }
+}
+/// Panic if the texture bound to `Dim` has an integer internal format, which cannot be linearly
+/// filtered.
+///
+/// Skipped if level parameters cannot be queried (GLES 3.0) or the base level is undefined.
+#[cfg(debug_assertions)]
+fn assert_not_integer<Dim: Dimensionality>(caller: &str) {
+    if !gl::GetTexLevelParameteriv::is_loaded() {
+        return;
+    }
+    // Level parameters of cubemaps are queried per-face.
+    let target = if Dim::TARGET == gl::TEXTURE_CUBE_MAP {
+        gl::TEXTURE_CUBE_MAP_POSITIVE_X
+    } else {
+        Dim::TARGET
+    };
+    let (mut base, mut internal_format) = (0, 0);
+    unsafe {
+        gl::GetTexParameteriv(Dim::TARGET, gl::TEXTURE_BASE_LEVEL, &mut base);
+        gl::GetTexLevelParameteriv(
+            target,
+            base,
+            gl::TEXTURE_INTERNAL_FORMAT,
+            &mut internal_format,
+        );
+    }
+    if let Some(internal_format) = InternalFormat::from_gl(internal_format as GLenum) {
+        assert!(
+            !internal_format.is_integer(),
+            "{caller}() linear filtering of integer format {internal_format:?} makes the texture incomplete"
+        );
+    }
+}
+/// Panic if `format` and `ty` do not pair with the internal format of `level` of the bound 2D
+/// texture, see [`check_transfer_pairs`].
+#[cfg(debug_assertions)]
+fn assert_transfer_pairs(level: u32, format: Format, ty: DataType) {
+    if let Err(mismatch) = check_transfer_pairs(level, format, ty) {
+        panic!("pixel transfer does not pair with texture format: {mismatch:?}");
+    }
+}
+/// Check that `format` and `ty` pair with the internal format of `level` of the bound 2D texture.
+///
+/// Skipped if level parameters cannot be queried (GLES 3.0) or the level is undefined, in which
+/// case the GL reports its own error.
+fn check_transfer_pairs(
+    level: u32,
+    format: Format,
+    ty: DataType,
+) -> Result<(), texture::FormatMismatch> {
+    if !gl::GetTexLevelParameteriv::is_loaded() {
+        return Ok(());
+    }
+    let level = level.try_into().unwrap();
+    let (mut width, mut internal_format) = (0, 0);
+    unsafe {
+        gl::GetTexLevelParameteriv(D2::TARGET, level, gl::TEXTURE_WIDTH, &mut width);
+        gl::GetTexLevelParameteriv(
+            D2::TARGET,
+            level,
+            gl::TEXTURE_INTERNAL_FORMAT,
+            &mut internal_format,
+        );
+    }
+    if width == 0 {
+        return Ok(());
+    }
+    match InternalFormat::from_gl(internal_format as GLenum) {
+        Some(internal_format) => internal_format.validate_transfer(format, ty),
+        None => Ok(()),
+    }
+}
+impl Active<D2Multisample> {
+    /// Define the format and size of a multisampled texture. Sample counts may be rounded up to
+    /// the nearest supported value, see [`GLHF::format_support`](crate::GLHF::format_support).
+    ///
+    /// `format` must be a sized, renderable format. If `fixed_sample_locations` is set, every
+    /// texel uses identical sample positions, as is required to share attachments with
+    /// renderbuffers in one framebuffer.
+    ///
+    /// The contents are undefined until rendered to.
+    #[doc(alias = "glTexStorage2DMultisample")]
+    pub fn storage_multisample(
+        &mut self,
+        format: InternalFormat,
+        width: NonZero<u32>,
+        height: NonZero<u32>,
+        samples: NonZero<u8>,
+        fixed_sample_locations: bool,
+    ) -> &mut Self {
+        unsafe {
+            gl::TexStorage2DMultisample(
+                D2Multisample::TARGET,
+                samples.get().into(),
+                format.as_gl(),
+                width.get().try_into().unwrap(),
+                height.get().try_into().unwrap(),
+                fixed_sample_locations.into(),
+            );
+        }
+        self
+    }
 }
 pub struct Slot<Dim: Dimensionality>(pub(crate) NotSync, pub(crate) core::marker::PhantomData<Dim>);
 impl<Dim: Dimensionality> Slot<Dim> {
     /// Bind a texture, returning an active token.
     #[doc(alias = "glBindTexture")]
     pub fn bind(&mut self, texture: &Texture<Dim>) -> &mut Active<Dim> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        {
+            crate::tracking::assert_live(texture);
+            crate::tracking::assert_texture_target(texture.0.get(), Dim::TARGET);
+        }
         unsafe { gl::BindTexture(Dim::TARGET, texture.0.get()) };
         super::zst_mut()
     }
     /// Bind a stateless texture, turning it into a `Texture` with the dimensionality of this slot.
+    ///
+    /// The returned `Active` can be used to configure the new texture in one go:
+    /// ```no_run
+    /// # use core::num::NonZero;
+    /// # let mut gl : glhf::GLHF = todo!();
+    /// # let [width, height] : [NonZero<u32>; 2] = todo!();
+    /// use glhf::texture::InternalFormat;
+    ///
+    /// let [stateless] = gl.new.textures();
+    /// let (texture, active) = gl.texture.d2.initialize(stateless);
+    /// active
+    ///     .storage(NonZero::<u32>::MIN, InternalFormat::Rgba8, width, height)
+    ///     .linear()
+    ///     .clamp();
+    /// ```
     #[doc(alias = "glBindTexture")]
     pub fn initialize(&mut self, texture: Stateless) -> (Texture<Dim>, &mut Active<Dim>) {
         // Transition the type to an initialized one
@@ -209,13 +573,57 @@ impl<Dim: Dimensionality> Slot<Dim> {
         unsafe { crate::gl_delete_with(gl::DeleteTextures, textures) }
     }
 }
+impl Slot<D2> {
+    /// Download `size` texels of a mip level of `texture` into host memory, starting from the
+    /// lower-left corner.
+    ///
+    /// GLES cannot read textures directly, so this attaches the level to a temporary framebuffer
+    /// and reads from that, see [`framebuffer::Active::read_pixels`] for the meaning of `format`
+    /// and `data`. Only color formats which may be rendered to can be read this way.
+    ///
+    /// The read framebuffer slot is left bound to the default framebuffer.
+    ///
+    /// # Errors
+    /// If `texture` cannot be attached, e.g. because it has a depth or non-renderable format.
+    ///
+    /// # Panics
+    /// See [`framebuffer::Active::read_pixels`].
+    #[doc(alias = "glReadPixels")]
+    #[doc(alias = "glFramebufferTexture2D")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn download(
+        &self,
+        read: &mut framebuffer::Slot<framebuffer::Read>,
+        pack: &buffer::Active<PixelPack, IsDefault>,
+        texture: &texture::Texture2D,
+        level: u32,
+        size: [u32; 2],
+        format: Format,
+        data: ImageDataMut,
+    ) -> Result<(), framebuffer::IncompleteErrorKind> {
+        let [temporary] = unsafe { crate::gl_gen_with(gl::GenFramebuffers) };
+        read.bind(&temporary)
+            .texture_2d(texture, crate::framebuffer::Attachment::Color0, level);
+        let (temporary, result) = match read.try_complete(temporary) {
+            Ok((complete, active)) => {
+                active.read_pixels(pack, [0, 0], size, format, data);
+                (complete.into(), Ok(()))
+            }
+            Err(error) => (error.framebuffer, Err(error.kind)),
+        };
+        // Deleting the bound framebuffer reverts the slot to the default.
+        unsafe { crate::gl_delete_with(gl::DeleteFramebuffers, [temporary]) };
+        result
+    }
+}
 
 pub type Slot2D = Slot<D2>;
 pub type Slot2DArray = Slot<D2Array>;
 pub type Slot3D = Slot<D3>;
 pub type SlotCube = Slot<Cube>;
+pub type Slot2DMultisample = Slot<D2Multisample>;
 
-/// Slots for binding textures. Corresponds to texture `glTex*` operations with `TEXTURE_{2D, 2D_ARRAY, 3D, CUBE_MAP}` targets.
+/// Slots for binding textures. Corresponds to texture `glTex*` operations with `TEXTURE_{2D, 2D_ARRAY, 3D, CUBE_MAP, 2D_MULTISAMPLE}` targets.
 pub struct Slots {
     /// `TEXTURE_2D`
     pub d2: Slot2D,
@@ -225,12 +633,25 @@ pub struct Slots {
     pub d2_array: Slot2DArray,
     /// `TEXTURE_CUBE_MAP`
     pub cube: SlotCube,
+    /// `TEXTURE_2D_MULTISAMPLE`, requires GLES 3.1.
+    pub d2_multisample: Slot2DMultisample,
 }
 impl Slots {
     /// Set the currently active texture unit. Corresponds to `glActiveTexture(GL_TEXTURE<slot>)`
     ///
     /// Each texture unit has its own current textures for all bind points. As such,
     /// this invalidates all [`Active`] texture handles.
+    ///
+    /// This is enforced statically: every [`Active`] handle borrows one of the slots within `self`,
+    /// so none may be held across a change of unit.
+    /// ```compile_fail
+    /// # fn f(gl: &mut glhf::GLHF, texture: &glhf::texture::Texture2D) {
+    /// let active = gl.texture.d2.bind(texture);
+    /// gl.texture.unit(1);
+    /// // Error - `active` would now refer to the binding of unit 1.
+    /// active.linear();
+    /// # }
+    /// ```
     #[doc(alias = "glActiveTexture")]
     pub fn unit(&mut self, slot: u32) -> &mut Self {
         unsafe {
@@ -238,6 +659,25 @@ impl Slots {
         }
         self
     }
+    /// Temporarily set the active texture unit, restoring the current one when the returned guard
+    /// is dropped.
+    ///
+    /// The guard dereferences to `self`, and borrows it for its whole lifetime, so no [`Active`]
+    /// texture handle from either unit may outlive the switch.
+    #[doc(alias = "glActiveTexture")]
+    #[doc(alias = "GL_ACTIVE_TEXTURE")]
+    pub fn unit_scoped(&mut self, slot: u32) -> UnitGuard<'_> {
+        let previous = unsafe {
+            let mut previous = core::mem::MaybeUninit::uninit();
+            gl::GetIntegerv(gl::ACTIVE_TEXTURE, previous.as_mut_ptr());
+            previous.assume_init()
+        };
+        self.unit(slot);
+        UnitGuard {
+            slots: self,
+            previous: previous as _,
+        }
+    }
     /// Delete textures. If any were bound to a slot, the slot becomes bound to the default texture.
     ///
     /// Use [`Into::into`] to convert textures into a deletion token. Alternatively, delete them
@@ -249,3 +689,30 @@ impl Slots {
         unsafe { crate::gl_delete_with(gl::DeleteTextures, textures) }
     }
 }
+
+/// Restores the previously active texture unit on drop. See [`Slots::unit_scoped`].
+pub struct UnitGuard<'slots> {
+    // Holding the slots ensures `Self::drop` has safe access to gl calls due to safety
+    // precondition of `crate::GLHF`, and that no `Active` outlives the switch.
+    slots: &'slots mut Slots,
+    /// The `GL_TEXTURE<n>` enum to restore.
+    previous: GLenum,
+}
+impl core::ops::Deref for UnitGuard<'_> {
+    type Target = Slots;
+    fn deref(&self) -> &Self::Target {
+        self.slots
+    }
+}
+impl core::ops::DerefMut for UnitGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.slots
+    }
+}
+impl Drop for UnitGuard<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            gl::ActiveTexture(self.previous);
+        }
+    }
+}
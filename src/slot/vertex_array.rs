@@ -1,9 +1,13 @@
 //! Binding and manipulating vertex array objects and their attribute definitions.
 use crate::{
-    gl,
+    buffer::Buffer,
+    gl::{
+        self,
+        types::{GLenum, GLint, GLsizeiptr},
+    },
     slot::marker::{IsDefault, NotDefault, Unknown},
     vertex_array::{self, VertexArray},
-    GLEnum, NotSync, ThinGLObject,
+    GLEnum, NonZero, NotSync, ThinGLObject,
 };
 
 // Note - GLES3.X techinically uses Default / NotDefault language here.
@@ -22,8 +26,11 @@ impl Active<NotDefault> {
     /// effectively inheriting the previous state. By default, attributes are disabled.
     ///
     /// # Panics
-    /// If the [`offset`](vertex_array::Attribute::offset) does not fit align requirements
-    /// for it's type.
+    /// * If the [`offset`](vertex_array::Attribute::offset) does not fit align requirements
+    ///   for it's type.
+    /// * If the offset or stride is too large for the GL to address, as can happen
+    ///   on 32-bit targets.
+    /// * If a packed type is used with fewer than four components.
     #[doc(alias = "glVertexAttribPointer")]
     #[doc(alias = "glVertexAttribIPointer")]
     pub fn attribute(
@@ -34,10 +41,19 @@ impl Active<NotDefault> {
         enable: Option<bool>,
     ) -> &mut Self {
         use vertex_array::AttributeType;
+        // The GL adds to the offset as it fetches - make sure the first value at least is addressable.
+        assert!(
+            attribute
+                .offset
+                .checked_add(attribute.value_size())
+                .is_some_and(|end| GLsizeiptr::try_from(end).is_ok()),
+            "attribute offset overflows"
+        );
+        attribute.ty.assert_components(attribute.components);
         let size = attribute.components.into();
-        let stride = attribute
-            .stride
-            .map_or(0, |stride| stride.get().try_into().unwrap());
+        let stride = attribute.stride.map_or(0, |stride| {
+            stride.get().try_into().expect("attribute stride overflows")
+        });
 
         // Safety - hoooh boy...
         // This pointer type is interpreted as a numeric offset as long as there's a array buffer bound -
@@ -89,6 +105,114 @@ impl Active<NotDefault> {
             self
         }
     }
+    /// Set the layout of a vertex attribute slot, separately from the buffer it is fetched from,
+    /// and route it to fetch from the buffer at `binding`. Requires GLES 3.1.
+    ///
+    /// Values are fetched from `relative_offset` bytes past the start of each vertex in the buffer
+    /// given by [`Self::bind_vertex_buffer`]. Unlike [`Self::attribute`], changing that buffer does
+    /// not require re-specifying the format.
+    ///
+    /// # Panics
+    /// * If `relative_offset` does not fit align requirements for `ty`.
+    /// * If a packed `ty` is used with fewer than four components.
+    #[doc(alias = "glVertexAttribFormat")]
+    #[doc(alias = "glVertexAttribIFormat")]
+    #[doc(alias = "glVertexAttribBinding")]
+    pub fn attribute_format(
+        &mut self,
+        index: u32,
+        ty: vertex_array::AttributeType,
+        components: vertex_array::Components,
+        relative_offset: u32,
+        binding: u32,
+    ) -> &mut Self {
+        use vertex_array::AttributeType;
+        ty.assert_components(components);
+        let size = components.into();
+
+        assert_eq!(
+            usize::try_from(relative_offset).unwrap() % ty.align_of(),
+            0,
+            "attribute offset must be aligned"
+        );
+
+        match ty {
+            AttributeType::Integer(ty) => unsafe {
+                gl::VertexAttribIFormat(index, size, ty.as_gl(), relative_offset);
+            },
+            AttributeType::Float(ty) => unsafe {
+                gl::VertexAttribFormat(index, size, ty.as_gl(), gl::FALSE, relative_offset);
+            },
+            AttributeType::PackedScaled(ty) => unsafe {
+                gl::VertexAttribFormat(index, size, ty.as_gl(), gl::FALSE, relative_offset);
+            },
+            AttributeType::Scaled(ty) => unsafe {
+                gl::VertexAttribFormat(index, size, ty.as_gl(), gl::FALSE, relative_offset);
+            },
+            AttributeType::Normalized(ty) => unsafe {
+                gl::VertexAttribFormat(index, size, ty.as_gl(), gl::TRUE, relative_offset);
+            },
+            AttributeType::PackedNormalized(ty) => unsafe {
+                gl::VertexAttribFormat(index, size, ty.as_gl(), gl::TRUE, relative_offset);
+            },
+        }
+        self.attribute_binding(index, binding)
+    }
+    /// Route the attribute at `index` to fetch from the buffer at `binding`. Requires GLES 3.1.
+    #[doc(alias = "glVertexAttribBinding")]
+    pub fn attribute_binding(&mut self, index: u32, binding: u32) -> &mut Self {
+        unsafe {
+            gl::VertexAttribBinding(index, binding);
+        }
+        self
+    }
+    /// Set the buffer that attributes routed to `binding` fetch from. Requires GLES 3.1.
+    ///
+    /// Vertices begin `offset` bytes into the buffer, and are `stride` bytes apart. A `stride` of
+    /// zero fetches the same vertex repeatedly. The buffer is remembered internally, and does not need
+    /// to be active at time of draw.
+    #[doc(alias = "glBindVertexBuffer")]
+    pub fn bind_vertex_buffer(
+        &mut self,
+        binding: u32,
+        buffer: &Buffer,
+        offset: usize,
+        stride: usize,
+    ) -> &mut Self {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(buffer);
+        unsafe {
+            gl::BindVertexBuffer(
+                binding,
+                buffer.name().get(),
+                offset.try_into().unwrap(),
+                stride.try_into().unwrap(),
+            );
+        }
+        self
+    }
+    /// Set the rate at which attributes routed to `binding` advance during instanced draws.
+    /// Requires GLES 3.1.
+    ///
+    /// This is the counterpart of [`Self::attribute_divisor`] for the [`Self::bind_vertex_buffer`] model.
+    #[doc(alias = "glVertexBindingDivisor")]
+    pub fn vertex_binding_divisor(&mut self, binding: u32, divisor: u32) -> &mut Self {
+        unsafe {
+            gl::VertexBindingDivisor(binding, divisor);
+        }
+        self
+    }
+    /// Set the rate at which the attribute at `index` advances during instanced draws.
+    ///
+    /// A divisor of zero (the default) advances once per vertex. Otherwise, the attribute
+    /// advances once per `divisor` instances.
+    #[doc(alias = "glVertexAttribDivisor")]
+    pub fn attribute_divisor(&mut self, index: u32, divisor: u32) -> &mut Self {
+        unsafe {
+            gl::VertexAttribDivisor(index, divisor);
+        }
+        self
+    }
     /// Enable or disable the attribute at `index`. By default, all attributes are disabled.
     #[doc(alias = "glEnableVertexAttribArray")]
     #[doc(alias = "glDisableVertexAttribArray")]
@@ -106,6 +230,103 @@ impl Active<NotDefault> {
     }
 }
 
+impl<Kind> Active<Kind> {
+    /// Zero if `index` is out of range.
+    unsafe fn vertex_attrib_parameter(index: u32, pname: GLenum) -> GLint {
+        unsafe {
+            let mut value = 0;
+            gl::GetVertexAttribiv(index, pname, &mut value);
+            value
+        }
+    }
+    /// Read back the state of the attribute at `index`, e.g. to verify the layout of a vertex array
+    /// configured outside of this crate.
+    ///
+    /// An `index` of `GL_MAX_VERTEX_ATTRIBS` or greater reads as a disabled, unset attribute.
+    ///
+    /// This is not cached and invokes several `glGet`s.
+    #[doc(alias = "glGetVertexAttribiv")]
+    #[doc(alias = "glGetVertexAttribPointerv")]
+    #[doc(alias = "GL_VERTEX_ATTRIB_ARRAY_ENABLED")]
+    #[doc(alias = "GL_VERTEX_ATTRIB_ARRAY_SIZE")]
+    #[doc(alias = "GL_VERTEX_ATTRIB_ARRAY_TYPE")]
+    #[doc(alias = "GL_VERTEX_ATTRIB_ARRAY_STRIDE")]
+    #[doc(alias = "GL_VERTEX_ATTRIB_ARRAY_DIVISOR")]
+    #[doc(alias = "GL_VERTEX_ATTRIB_ARRAY_BUFFER_BINDING")]
+    #[must_use]
+    pub fn get_attribute(&self, index: u32) -> vertex_array::AttributeState {
+        use vertex_array::{
+            AttributeType, Components, FloatingAttribute, IntegerAttribute, PackedIntegerAttribute,
+        };
+        let get = |pname| unsafe { Self::vertex_attrib_parameter(index, pname) };
+        let flag = |pname| get(pname) == gl::TRUE.into();
+
+        let offset = unsafe {
+            let mut pointer = core::ptr::null_mut();
+            // The binding declares this `*const`, but it is written through.
+            let out = core::ptr::addr_of_mut!(pointer);
+            gl::GetVertexAttribPointerv(index, gl::VERTEX_ATTRIB_ARRAY_POINTER, out);
+            // A byte offset masquerading as a pointer, see `Self::attribute`.
+            pointer as usize
+        };
+
+        let components = match get(gl::VERTEX_ATTRIB_ARRAY_SIZE) {
+            1 => Some(Components::Scalar),
+            2 => Some(Components::Vec2),
+            3 => Some(Components::Vec3),
+            4 => Some(Components::Vec4),
+            _ => None,
+        };
+        let integer = |ty: GLenum| match ty {
+            gl::UNSIGNED_BYTE => Some(IntegerAttribute::U8),
+            gl::BYTE => Some(IntegerAttribute::I8),
+            gl::UNSIGNED_SHORT => Some(IntegerAttribute::U16),
+            gl::SHORT => Some(IntegerAttribute::I16),
+            gl::UNSIGNED_INT => Some(IntegerAttribute::U32),
+            gl::INT => Some(IntegerAttribute::I32),
+            _ => None,
+        };
+        let ty = get(gl::VERTEX_ATTRIB_ARRAY_TYPE) as GLenum;
+        let is_integer = flag(gl::VERTEX_ATTRIB_ARRAY_INTEGER);
+        let normalized = flag(gl::VERTEX_ATTRIB_ARRAY_NORMALIZED);
+        let packed = match ty {
+            gl::INT_2_10_10_10_REV => Some(PackedIntegerAttribute::IReverse2_10_10_10),
+            gl::UNSIGNED_INT_2_10_10_10_REV => Some(PackedIntegerAttribute::UReverse2_10_10_10),
+            _ => None,
+        };
+        let ty = match ty {
+            gl::HALF_FLOAT => Some(AttributeType::Float(FloatingAttribute::F16)),
+            gl::FLOAT => Some(AttributeType::Float(FloatingAttribute::F32)),
+            gl::FIXED => Some(AttributeType::Float(FloatingAttribute::Fixed16_16)),
+            _ if is_integer => integer(ty).map(AttributeType::Integer),
+            _ if normalized => integer(ty)
+                .map(AttributeType::Normalized)
+                .or(packed.map(AttributeType::PackedNormalized)),
+            _ => integer(ty)
+                .map(AttributeType::Scaled)
+                .or(packed.map(AttributeType::PackedScaled)),
+        };
+
+        vertex_array::AttributeState {
+            enabled: flag(gl::VERTEX_ATTRIB_ARRAY_ENABLED),
+            attribute: ty
+                .zip(components)
+                .map(|(ty, components)| vertex_array::Attribute {
+                    ty,
+                    components,
+                    stride: NonZero::new(get(gl::VERTEX_ATTRIB_ARRAY_STRIDE).try_into().unwrap()),
+                    offset,
+                }),
+            divisor: get(gl::VERTEX_ATTRIB_ARRAY_DIVISOR).try_into().unwrap(),
+            buffer: NonZero::new(
+                get(gl::VERTEX_ATTRIB_ARRAY_BUFFER_BINDING)
+                    .try_into()
+                    .unwrap(),
+            ),
+        }
+    }
+}
+
 /// Entry points for `gl*VertexAttrib*`.
 pub struct Active<Kind>(core::marker::PhantomData<Kind>);
 pub struct Slot(pub(crate) NotSync);
@@ -113,6 +334,8 @@ impl Slot {
     /// Bind a user-defined array to this slot.
     #[doc(alias = "glBindVertexArray")]
     pub fn bind(&mut self, array: &VertexArray) -> &mut Active<NotDefault> {
+        #[cfg(all(debug_assertions, feature = "debug-object-tracking"))]
+        crate::tracking::assert_live(array);
         unsafe {
             gl::BindVertexArray(array.name().get());
         }
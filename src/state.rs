@@ -1,7 +1,7 @@
 //! Manipulation of global GL state.
-use super::{gl, GLEnum, NotSync};
+use super::{gl, GLEnum, GLenum, NotSync};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct Color {
     pub r: f32,
     pub g: f32,
@@ -14,7 +14,7 @@ impl From<[f32; 4]> for Color {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct ColorMask {
     pub r: bool,
     pub g: bool,
@@ -37,8 +37,34 @@ impl From<bool> for ColorMask {
     }
 }
 
+/// A rectangle of window coordinates, for [`State::viewport_rect`] and [`State::scissor_rect`].
+///
+/// Converts from an `(x, y, width, height)` tuple.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rect {
+    /// The lower-left corner.
+    pub origin: [i32; 2],
+    pub size: [u32; 2],
+}
+impl Rect {
+    #[must_use]
+    pub const fn new(origin: [i32; 2], size: [u32; 2]) -> Self {
+        Self { origin, size }
+    }
+    /// A rectangle of the given size, with its lower-left corner at the origin.
+    #[must_use]
+    pub const fn from_size(size: [u32; 2]) -> Self {
+        Self::new([0; 2], size)
+    }
+}
+impl From<(i32, i32, u32, u32)> for Rect {
+    fn from((x, y, width, height): (i32, i32, u32, u32)) -> Self {
+        Self::new([x, y], [width, height])
+    }
+}
+
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum CompareFunc {
     LessEqual = gl::LEQUAL,
     GreaterEqual = gl::GEQUAL,
@@ -51,9 +77,24 @@ pub enum CompareFunc {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for CompareFunc {}
+impl CompareFunc {
+    fn from_gl(value: GLenum) -> Self {
+        match value {
+            gl::LEQUAL => Self::LessEqual,
+            gl::GEQUAL => Self::GreaterEqual,
+            gl::LESS => Self::Less,
+            gl::GREATER => Self::Greater,
+            gl::EQUAL => Self::Equal,
+            gl::NOTEQUAL => Self::NotEqual,
+            gl::ALWAYS => Self::Always,
+            gl::NEVER => Self::Never,
+            _ => panic!("unknown compare func {value:#x}"),
+        }
+    }
+}
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum CullFace {
     Front = gl::FRONT,
     Back = gl::BACK,
@@ -63,18 +104,44 @@ pub enum CullFace {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for CullFace {}
+impl CullFace {
+    fn from_gl(value: GLenum) -> Self {
+        match value {
+            gl::FRONT => Self::Front,
+            gl::BACK => Self::Back,
+            gl::FRONT_AND_BACK => Self::FrontAndBack,
+            _ => panic!("unknown cull face {value:#x}"),
+        }
+    }
+}
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum FrontFace {
     Clockwise = gl::CW,
     CounterClockwise = gl::CCW,
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for FrontFace {}
+impl FrontFace {
+    fn from_gl(value: GLenum) -> Self {
+        match value {
+            gl::CW => Self::Clockwise,
+            gl::CCW => Self::CounterClockwise,
+            _ => panic!("unknown front face {value:#x}"),
+        }
+    }
+}
+
+/// The corner of a point at which `gl_PointCoord` is `(0, 0)`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PointCoordOrigin {
+    UpperLeft,
+    LowerLeft,
+}
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum BlendEquation {
     /// `(src * factor) + (dst * factor)`
     Add = gl::FUNC_ADD,
@@ -89,9 +156,22 @@ pub enum BlendEquation {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for BlendEquation {}
+impl BlendEquation {
+    /// `None` for equations this crate does not model, such as the GLES 3.2 advanced equations.
+    fn from_gl(value: GLenum) -> Option<Self> {
+        Some(match value {
+            gl::FUNC_ADD => Self::Add,
+            gl::FUNC_SUBTRACT => Self::Subtract,
+            gl::FUNC_REVERSE_SUBTRACT => Self::ReverseSubtract,
+            gl::MIN => Self::Min,
+            gl::MAX => Self::Max,
+            _ => return None,
+        })
+    }
+}
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum BlendFactor {
     Zero = gl::ZERO,
     One = gl::ONE,
@@ -115,11 +195,56 @@ pub enum BlendFactor {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for BlendFactor {}
+impl BlendFactor {
+    /// `None` for factors this crate does not model, such as the dual-source factors of
+    /// `GL_EXT_blend_func_extended`.
+    fn from_gl(value: GLenum) -> Option<Self> {
+        Some(match value {
+            gl::ZERO => Self::Zero,
+            gl::ONE => Self::One,
+            gl::SRC_COLOR => Self::SrcColor,
+            gl::ONE_MINUS_SRC_COLOR => Self::OneMinusSrcColor,
+            gl::SRC_ALPHA => Self::SrcAlpha,
+            gl::ONE_MINUS_SRC_ALPHA => Self::OneMinusSrcAlpha,
+            gl::DST_COLOR => Self::DstColor,
+            gl::ONE_MINUS_DST_COLOR => Self::OneMinusDstColor,
+            gl::DST_ALPHA => Self::DstAlpha,
+            gl::ONE_MINUS_DST_ALPHA => Self::OneMinusDstAlpha,
+            gl::CONSTANT_COLOR => Self::ConstantColor,
+            gl::ONE_MINUS_CONSTANT_COLOR => Self::OneMinusConstantColor,
+            gl::CONSTANT_ALPHA => Self::ConstantAlpha,
+            gl::ONE_MINUS_CONSTANT_ALPHA => Self::OneMinusConstantAlpha,
+            gl::SRC_ALPHA_SATURATE => Self::SrcAlphaSaturate,
+            _ => return None,
+        })
+    }
+}
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct BlendFunc {
-    src_factor: BlendFactor,
-    dst_factor: BlendFactor,
+    /// Factor applied to the fragment's output color.
+    pub src_factor: BlendFactor,
+    /// Factor applied to the color already in the draw buffer.
+    pub dst_factor: BlendFactor,
+}
+
+/// Blending of a single draw buffer, see [`BlendState`]. The fields correspond to the
+/// arguments of [`State::blend_equation`] and [`State::blend_func`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BufferBlend {
+    pub equation: BlendEquation,
+    pub alpha_equation: Option<BlendEquation>,
+    pub func: BlendFunc,
+    pub alpha_func: Option<BlendFunc>,
+}
+
+/// Blending of every draw buffer at once, applied by [`State::apply_blend_state`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct BlendState {
+    /// Indexed by draw buffer, i.e. position within
+    /// [`draw_buffers`](crate::slot::framebuffer::Active::draw_buffers) rather than attachment.
+    /// `None` disables blending for that buffer.
+    pub buffers: [Option<BufferBlend>; 4],
 }
 
 /// Arguments to `gl{Enable, Disable}`.
@@ -163,6 +288,8 @@ pub enum Capability {
     RasterizerDiscard = gl::RASTERIZER_DISCARD,
     /// Bitwise `AND` the fragment coverage value with a temporary mask based on the
     /// alpha of each sample. This can be used for cheap approximate order-independent transparency.
+    ///
+    /// See [`State::alpha_to_coverage_cutout`].
     SampleAlphaToCoverage = gl::SAMPLE_ALPHA_TO_COVERAGE,
     /// Bitwise `AND` the fragment coverage value with a user-defined mask.
     SampleMask = gl::SAMPLE_MASK,
@@ -179,7 +306,7 @@ pub enum Capability {
 unsafe impl crate::GLEnum for Capability {}
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum StencilOp {
     Keep = gl::KEEP,
     Zero = gl::ZERO,
@@ -196,10 +323,164 @@ pub enum StencilOp {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for StencilOp {}
+impl StencilOp {
+    fn from_gl(value: GLenum) -> Self {
+        match value {
+            gl::KEEP => Self::Keep,
+            gl::ZERO => Self::Zero,
+            gl::REPLACE => Self::Replace,
+            gl::INCR => Self::SaturatingIncrement,
+            gl::INCR_WRAP => Self::WrappingIncrement,
+            gl::DECR => Self::SaturatingDecrement,
+            gl::DECR_WRAP => Self::WrappingDecrement,
+            gl::INVERT => Self::Invert,
+            _ => panic!("unknown stencil op {value:#x}"),
+        }
+    }
+}
+
+/// Selects the stencil state of front- or back-facing polygons.
+///
+/// Points, lines, and polygons with neither facing use the front state.
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Face {
+    Front = gl::FRONT,
+    Back = gl::BACK,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for Face {}
+
+/// Buffer targets with an array of binding points, selected by index in shaders.
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum IndexedTarget {
+    Uniform = gl::UNIFORM_BUFFER,
+    TransformFeedback = gl::TRANSFORM_FEEDBACK_BUFFER,
+    /// Requires GLES 3.1.
+    ShaderStorage = gl::SHADER_STORAGE_BUFFER,
+    /// Requires GLES 3.1.
+    AtomicCounter = gl::ATOMIC_COUNTER_BUFFER,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for IndexedTarget {}
+impl IndexedTarget {
+    /// The `(binding, start, size)` pnames for this target.
+    fn pnames(self) -> [GLenum; 3] {
+        match self {
+            Self::Uniform => [
+                gl::UNIFORM_BUFFER_BINDING,
+                gl::UNIFORM_BUFFER_START,
+                gl::UNIFORM_BUFFER_SIZE,
+            ],
+            Self::TransformFeedback => [
+                gl::TRANSFORM_FEEDBACK_BUFFER_BINDING,
+                gl::TRANSFORM_FEEDBACK_BUFFER_START,
+                gl::TRANSFORM_FEEDBACK_BUFFER_SIZE,
+            ],
+            Self::ShaderStorage => [
+                gl::SHADER_STORAGE_BUFFER_BINDING,
+                gl::SHADER_STORAGE_BUFFER_START,
+                gl::SHADER_STORAGE_BUFFER_SIZE,
+            ],
+            Self::AtomicCounter => [
+                gl::ATOMIC_COUNTER_BUFFER_BINDING,
+                gl::ATOMIC_COUNTER_BUFFER_START,
+                gl::ATOMIC_COUNTER_BUFFER_SIZE,
+            ],
+        }
+    }
+}
+
+/// A buffer bound to an indexed binding point, as read back by [`State::indexed_buffer_binding`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct IndexedBinding {
+    /// The name of the bound buffer.
+    pub buffer: crate::NonZeroName,
+    /// The offset in bytes of the bound range.
+    pub offset: usize,
+    /// The length in bytes of the bound range, zero if the whole buffer is bound.
+    pub size: usize,
+}
+
+/// `glGet` a rectangle parameter, such as `GL_VIEWPORT`.
+fn get_rect(pname: GLenum) -> Rect {
+    let mut rect = [0; 4];
+    unsafe {
+        gl::GetIntegerv(pname, rect.as_mut_ptr());
+    }
+    let [x, y, width, height] = rect;
+    Rect::new(
+        [x, y],
+        [width.try_into().unwrap(), height.try_into().unwrap()],
+    )
+}
+/// `glGet` a single integer parameter, such as an enum or bitmask, reinterpreting the bits as unsigned.
+fn get_unsigned(pname: GLenum) -> GLenum {
+    let value = unsafe {
+        let mut value = core::mem::MaybeUninit::uninit();
+        gl::GetIntegerv(pname, value.as_mut_ptr());
+        value.assume_init()
+    };
+    value as _
+}
 
 /// Read and write global state.
 pub struct State(pub(crate) NotSync);
 impl State {
+    /// Configure for rendering alpha-tested cutouts, such as foliage, with antialiased edges.
+    /// This enables [`Capability::SampleAlphaToCoverage`] and disables [`Capability::Blend`].
+    ///
+    /// GLES 3 has no fixed-function alpha test. The usual replacement is a `discard` in the
+    /// fragment shader for fragments below some alpha threshold, which works on any framebuffer
+    /// but aliases along the cutout's edges. On a multisampled framebuffer, alpha-to-coverage
+    /// instead converts each fragment's alpha into a partial sample mask, smoothing the edges
+    /// without needing to sort geometry as blending would. For this to look right, the fragment
+    /// shader should output an alpha which ramps sharply around the threshold rather than using
+    /// `discard`.
+    ///
+    /// Alpha-to-coverage does nothing on a framebuffer without [samples](Self::samples), so
+    /// a `discard` path is still needed where that may occur. The setting is global, so it may
+    /// be enabled before any multisampled framebuffer is bound.
+    #[doc(alias = "glEnable")]
+    #[doc(alias = "glDisable")]
+    #[doc(alias = "GL_SAMPLE_ALPHA_TO_COVERAGE")]
+    pub fn alpha_to_coverage_cutout(&self) -> &Self {
+        self.enable(Capability::SampleAlphaToCoverage)
+            .disable(Capability::Blend)
+    }
+    /// Enable, disable, and configure blending of each draw buffer individually.
+    ///
+    /// Requires GLES 3.2.
+    ///
+    /// # Panics
+    /// If the GLES 3.2 entry points were not loaded.
+    #[doc(alias = "glEnablei")]
+    #[doc(alias = "glDisablei")]
+    #[doc(alias = "glBlendEquationSeparatei")]
+    #[doc(alias = "glBlendFuncSeparatei")]
+    pub fn apply_blend_state(&self, state: &BlendState) -> &Self {
+        for (index, blend) in (0..).zip(&state.buffers) {
+            let Some(blend) = blend else {
+                unsafe { gl::Disablei(gl::BLEND, index) };
+                continue;
+            };
+            let alpha_equation = blend.alpha_equation.unwrap_or(blend.equation);
+            let alpha_func = blend.alpha_func.unwrap_or(blend.func);
+            unsafe {
+                gl::Enablei(gl::BLEND, index);
+                gl::BlendEquationSeparatei(index, blend.equation.as_gl(), alpha_equation.as_gl());
+                gl::BlendFuncSeparatei(
+                    index,
+                    blend.func.src_factor.as_gl(),
+                    blend.func.dst_factor.as_gl(),
+                    alpha_func.src_factor.as_gl(),
+                    alpha_func.dst_factor.as_gl(),
+                );
+            }
+        }
+        self
+    }
     /// Set the blend constant. Values are not clamped at a global level, but
     /// are clamped during blending when the destination buffer is an unsigned fixed-point format.
     #[doc(alias = "glBlendColor")]
@@ -231,6 +512,22 @@ impl State {
         }
         self
     }
+    /// Get the `(rgb, alpha)` equations last set by [`Self::blend_equation`]. Initially both
+    /// [`BlendEquation::Add`].
+    ///
+    /// `None` if either is an equation not modeled by [`BlendEquation`], as may be set outside of
+    /// this crate.
+    ///
+    /// This is not cached and invokes two `glGet`s.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_BLEND_EQUATION_RGB")]
+    #[doc(alias = "GL_BLEND_EQUATION_ALPHA")]
+    #[must_use]
+    pub fn get_blend_equation(&self) -> Option<(BlendEquation, BlendEquation)> {
+        let [rgb, alpha] = [gl::BLEND_EQUATION_RGB, gl::BLEND_EQUATION_ALPHA]
+            .map(|pname| BlendEquation::from_gl(get_unsigned(pname)));
+        Some((rgb?, alpha?))
+    }
     /// Set the multiplicative factors used to scale source and destination colors before
     /// being combined in the blend equation.
     /// If `alpha_func` is Some, separate factors are used for RGB and A. Otherwise, `func`
@@ -254,6 +551,38 @@ impl State {
         }
         self
     }
+    /// Get the `(rgb, alpha)` factors last set by [`Self::blend_func`]. Initially both
+    /// [`BlendFactor::One`] for the source and [`BlendFactor::Zero`] for the destination.
+    ///
+    /// `None` if any is a factor not modeled by [`BlendFactor`], as may be set outside of this
+    /// crate.
+    ///
+    /// This is not cached and invokes four `glGet`s.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_BLEND_SRC_RGB")]
+    #[doc(alias = "GL_BLEND_DST_RGB")]
+    #[doc(alias = "GL_BLEND_SRC_ALPHA")]
+    #[doc(alias = "GL_BLEND_DST_ALPHA")]
+    #[must_use]
+    pub fn get_blend_func(&self) -> Option<(BlendFunc, BlendFunc)> {
+        let [src_rgb, dst_rgb, src_alpha, dst_alpha] = [
+            gl::BLEND_SRC_RGB,
+            gl::BLEND_DST_RGB,
+            gl::BLEND_SRC_ALPHA,
+            gl::BLEND_DST_ALPHA,
+        ]
+        .map(|pname| BlendFactor::from_gl(get_unsigned(pname)));
+        Some((
+            BlendFunc {
+                src_factor: src_rgb?,
+                dst_factor: dst_rgb?,
+            },
+            BlendFunc {
+                src_factor: src_alpha?,
+                dst_factor: dst_alpha?,
+            },
+        ))
+    }
     /// What color value to clear color buffers to in a `glClear`.
     #[doc(alias = "glClearColor")]
     pub fn clear_color(&self, color: impl Into<Color>) -> &Self {
@@ -263,6 +592,19 @@ impl State {
         }
         self
     }
+    /// Get the color last set by [`Self::clear_color`]. Initially transparent black.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetFloatv")]
+    #[doc(alias = "GL_COLOR_CLEAR_VALUE")]
+    #[must_use]
+    pub fn get_clear_color(&self) -> Color {
+        let mut color = [0.0; 4];
+        unsafe {
+            gl::GetFloatv(gl::COLOR_CLEAR_VALUE, color.as_mut_ptr());
+        }
+        color.into()
+    }
     /// What floating point value to clear the depth buffer to in a `glClear`.
     #[doc(alias = "glClearDepth")]
     pub fn clear_depth(&self, depth: f32) -> &Self {
@@ -271,6 +613,19 @@ impl State {
         }
         self
     }
+    /// Get the depth last set by [`Self::clear_depth`], clamped to `[0, 1]`. Initially `1.0`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetFloatv")]
+    #[doc(alias = "GL_DEPTH_CLEAR_VALUE")]
+    #[must_use]
+    pub fn get_clear_depth(&self) -> f32 {
+        unsafe {
+            let mut depth = core::mem::MaybeUninit::uninit();
+            gl::GetFloatv(gl::DEPTH_CLEAR_VALUE, depth.as_mut_ptr());
+            depth.assume_init()
+        }
+    }
     /// What bit value to clear the stencil buffer to in a `glClear`.
     #[doc(alias = "glClearStencil")]
     pub fn clear_stencil(&self, stencil: u32) -> &Self {
@@ -280,6 +635,15 @@ impl State {
         }
         self
     }
+    /// Get the value last set by [`Self::clear_stencil`]. Initially `0`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_STENCIL_CLEAR_VALUE")]
+    #[must_use]
+    pub fn get_clear_stencil(&self) -> u32 {
+        get_unsigned(gl::STENCIL_CLEAR_VALUE)
+    }
     /// Enable or disable writes to color channels of all buffers.
     /// E.g., if `r` is `false`, drawing operations will not affect any red channels.
     ///
@@ -299,6 +663,19 @@ impl State {
         }
         self
     }
+    /// Get the mask last set by [`Self::color_mask`]. Initially all `true`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetBooleanv")]
+    #[doc(alias = "GL_COLOR_WRITEMASK")]
+    #[must_use]
+    pub fn get_color_mask(&self) -> ColorMask {
+        let mut write = [gl::FALSE; 4];
+        unsafe {
+            gl::GetBooleanv(gl::COLOR_WRITEMASK, write.as_mut_ptr());
+        }
+        write.map(|write| write == gl::TRUE).into()
+    }
     /// Which polygon faces to cull when [`Capability::CullFace`] is enabled
     #[doc(alias = "glCullFace")]
     pub fn cull_face(&self, face: CullFace) -> &Self {
@@ -307,6 +684,15 @@ impl State {
         }
         self
     }
+    /// Get the face last set by [`Self::cull_face`]. Initially [`CullFace::Back`].
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_CULL_FACE_MODE")]
+    #[must_use]
+    pub fn get_cull_face(&self) -> CullFace {
+        CullFace::from_gl(get_unsigned(gl::CULL_FACE_MODE))
+    }
     /// The function used to check a fragment's depth against the depth buffer.
     #[doc(alias = "glDepthFunc")]
     pub fn depth_func(&self, func: CompareFunc) -> &Self {
@@ -315,6 +701,15 @@ impl State {
         }
         self
     }
+    /// Get the function last set by [`Self::depth_func`]. Initially [`CompareFunc::Less`].
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_DEPTH_FUNC")]
+    #[must_use]
+    pub fn get_depth_func(&self) -> CompareFunc {
+        CompareFunc::from_gl(get_unsigned(gl::DEPTH_FUNC))
+    }
     /// Whether fragments that pass the fragment test should write to the depth buffer.
     ///
     /// This effects `Clear` commands.
@@ -325,6 +720,19 @@ impl State {
         }
         self
     }
+    /// Get the value last set by [`Self::depth_mask`]. Initially `true`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetBooleanv")]
+    #[doc(alias = "GL_DEPTH_WRITEMASK")]
+    #[must_use]
+    pub fn get_depth_mask(&self) -> bool {
+        unsafe {
+            let mut write = core::mem::MaybeUninit::uninit();
+            gl::GetBooleanv(gl::DEPTH_WRITEMASK, write.as_mut_ptr());
+            write.assume_init() == gl::TRUE
+        }
+    }
     /// Defines a linear mapping from [-1, 1] NDC space to `range` in depth map space.
     /// Range may be reversed, i.e. `1.0..=-1.0` is a valid range.
     #[doc(alias = "glDepthRangef")]
@@ -350,6 +758,15 @@ impl State {
         }
         self
     }
+    /// Query whether `capability` is enabled, as set by [`Self::enable`] and [`Self::disable`].
+    /// Initially, only [`Capability::Dither`] is enabled.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glIsEnabled")]
+    #[must_use]
+    pub fn is_enabled(&self, capability: Capability) -> bool {
+        unsafe { gl::IsEnabled(capability.as_gl()) == gl::TRUE }
+    }
     /// Defines what winding order, in framebuffer space, is consindered the "front" of a polygon.
     #[doc(alias = "glFrontFace")]
     pub fn front_face(&self, winding: FrontFace) -> &Self {
@@ -358,6 +775,120 @@ impl State {
         }
         self
     }
+    /// Get the winding last set by [`Self::front_face`]. Initially [`FrontFace::CounterClockwise`].
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_FRONT_FACE")]
+    #[must_use]
+    pub fn get_front_face(&self) -> FrontFace {
+        FrontFace::from_gl(get_unsigned(gl::FRONT_FACE))
+    }
+    /// `glGet` a single float parameter not otherwise wrapped by this crate.
+    ///
+    /// This is a low-level escape hatch - prefer the typed getters where they exist. Parameters
+    /// may be found in the raw `glhf::gl` module, e.g. `gl::MAX_TEXTURE_LOD_BIAS`. An unknown
+    /// `pname` generates `GL_INVALID_ENUM` and returns zero.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    ///
+    /// # Safety
+    /// `pname` must name a parameter of exactly one value.
+    #[doc(alias = "glGetFloatv")]
+    #[must_use]
+    pub unsafe fn get_float(&self, pname: GLenum) -> f32 {
+        let [value] = unsafe { self.get_floats(pname) };
+        value
+    }
+    /// `glGet` a parameter of `N` floats, such as `GL_ALIASED_POINT_SIZE_RANGE`. See
+    /// [`Self::get_float`].
+    ///
+    /// # Safety
+    /// `pname` must name a parameter of at most `N` values.
+    #[doc(alias = "glGetFloatv")]
+    #[must_use]
+    pub unsafe fn get_floats<const N: usize>(&self, pname: GLenum) -> [f32; N] {
+        // Zeroed, as the GL writes nothing on error.
+        let mut values = [0.0; N];
+        unsafe {
+            gl::GetFloatv(pname, values.as_mut_ptr());
+        }
+        values
+    }
+    /// `glGet` a single integer parameter not otherwise wrapped by this crate, such as an
+    /// implementation limit.
+    ///
+    /// This is a low-level escape hatch - prefer the typed getters where they exist. Parameters
+    /// may be found in the raw `glhf::gl` module, e.g. `gl::MAX_TEXTURE_SIZE`. An unknown `pname`
+    /// generates `GL_INVALID_ENUM` and returns zero.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    ///
+    /// # Safety
+    /// `pname` must name a parameter of exactly one value.
+    #[doc(alias = "glGetInteger64v")]
+    #[must_use]
+    pub unsafe fn get_int(&self, pname: GLenum) -> i64 {
+        let [value] = unsafe { self.get_ints(pname) };
+        value
+    }
+    /// `glGet` a parameter of `N` integers, such as `GL_MAX_VIEWPORT_DIMS`. See
+    /// [`Self::get_int`].
+    ///
+    /// # Safety
+    /// `pname` must name a parameter of at most `N` values.
+    #[doc(alias = "glGetInteger64v")]
+    #[must_use]
+    pub unsafe fn get_ints<const N: usize>(&self, pname: GLenum) -> [i64; N] {
+        // Zeroed, as the GL writes nothing on error.
+        let mut values = [0; N];
+        unsafe {
+            gl::GetInteger64v(pname, values.as_mut_ptr());
+        }
+        values
+    }
+    /// Get the buffer bound to binding point `index` of `target`, if any.
+    ///
+    /// This is not cached and invokes three `glGet`s.
+    ///
+    /// # Panics
+    /// If `index` is not less than the number of binding points of `target`, e.g.
+    /// `GL_MAX_UNIFORM_BUFFER_BINDINGS`.
+    #[doc(alias = "glGetIntegeri_v")]
+    #[doc(alias = "glGetInteger64i_v")]
+    #[doc(alias = "GL_UNIFORM_BUFFER_BINDING")]
+    #[doc(alias = "GL_TRANSFORM_FEEDBACK_BUFFER_BINDING")]
+    #[doc(alias = "GL_SHADER_STORAGE_BUFFER_BINDING")]
+    #[doc(alias = "GL_ATOMIC_COUNTER_BUFFER_BINDING")]
+    #[must_use]
+    pub fn indexed_buffer_binding(
+        &self,
+        target: IndexedTarget,
+        index: u32,
+    ) -> Option<IndexedBinding> {
+        let [binding, start, size] = target.pnames();
+        // Sentinel, as the GL writes nothing on error.
+        let mut buffer = -1;
+        let mut offset = 0;
+        let mut len = 0;
+        unsafe {
+            gl::GetIntegeri_v(binding, index, &mut buffer);
+        }
+        assert_ne!(
+            buffer, -1,
+            "binding index {index} out of range for {target:?}"
+        );
+        let buffer = crate::NonZeroName::new(buffer.try_into().unwrap())?;
+        unsafe {
+            gl::GetInteger64i_v(start, index, &mut offset);
+            gl::GetInteger64i_v(size, index, &mut len);
+        }
+        Some(IndexedBinding {
+            buffer,
+            offset: offset.try_into().unwrap(),
+            size: len.try_into().unwrap(),
+        })
+    }
     #[doc(alias = "glLineWidth")]
     pub fn line_width(&self, width: f32) -> &Self {
         unsafe {
@@ -365,6 +896,78 @@ impl State {
         }
         self
     }
+    /// Set the width of rasterized lines, clamped to the implementation's
+    /// `GL_ALIASED_LINE_WIDTH_RANGE`, returning the width actually used.
+    ///
+    /// Many implementations only support a width of `1.0`, in which case wider lines would
+    /// otherwise silently fall back.
+    ///
+    /// This invokes a `glGet` every call.
+    #[doc(alias = "glLineWidth")]
+    #[doc(alias = "GL_ALIASED_LINE_WIDTH_RANGE")]
+    pub fn line_width_clamped(&self, width: f32) -> f32 {
+        let [min, max] = unsafe {
+            let mut range = [0.0; 2];
+            gl::GetFloatv(gl::ALIASED_LINE_WIDTH_RANGE, range.as_mut_ptr());
+            range
+        };
+        let width = width.clamp(min, max);
+        self.line_width(width);
+        width
+    }
+    /// Set the number of vertices making up each patch for [`Topology::Patches`](crate::draw::Topology::Patches)
+    /// draws. Initially `3`.
+    ///
+    /// `count` must be non-zero and no greater than `GL_MAX_PATCH_VERTICES`, which is at least 32.
+    ///
+    /// # Panics
+    /// * If the GLES 3.2 entry point was not loaded.
+    /// * In debug builds, if `count` is out of range.
+    #[doc(alias = "glPatchParameteri")]
+    #[doc(alias = "GL_PATCH_VERTICES")]
+    pub fn patch_vertices(&self, count: u32) -> &Self {
+        #[cfg(debug_assertions)]
+        {
+            // Left at zero below GLES 3.2, where the limit is unknown.
+            let max = unsafe {
+                let mut max = 0;
+                gl::GetIntegerv(gl::MAX_PATCH_VERTICES, &mut max);
+                max
+            };
+            assert!(
+                count != 0 && (max == 0 || count <= max.try_into().unwrap()),
+                "patch vertex count {count} out of range 1..={max}"
+            );
+        }
+        unsafe {
+            gl::PatchParameteri(gl::PATCH_VERTICES, count.try_into().unwrap());
+        }
+        self
+    }
+    /// The corner of a [point](crate::draw::Topology::Points) at which `gl_PointCoord` is `(0, 0)`.
+    ///
+    /// GLES fixes this at [`PointCoordOrigin::UpperLeft`], unlike desktop GL where it is configurable -
+    /// this is provided for code shared between the two.
+    #[doc(alias = "GL_POINT_SPRITE_COORD_ORIGIN")]
+    #[must_use]
+    pub fn point_coord_origin(&self) -> PointCoordOrigin {
+        PointCoordOrigin::UpperLeft
+    }
+    /// Get the range of `gl_PointSize`s supported by the implementation. Sizes outside of
+    /// this range are clamped. The range always includes `1.0`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetFloatv")]
+    #[doc(alias = "GL_ALIASED_POINT_SIZE_RANGE")]
+    #[must_use]
+    pub fn point_size_range(&self) -> core::ops::RangeInclusive<f32> {
+        let [min, max] = unsafe {
+            let mut range = [0.0; 2];
+            gl::GetFloatv(gl::ALIASED_POINT_SIZE_RANGE, range.as_mut_ptr());
+            range
+        };
+        min..=max
+    }
     #[doc(alias = "glPolygonOffset")]
     pub fn polygon_offset(&self, factor: f32, units: f32) -> &Self {
         unsafe {
@@ -372,6 +975,50 @@ impl State {
         }
         self
     }
+    /// [`Self::polygon_offset`], with the total offset limited to at most `clamp` in magnitude
+    /// (or at least, for negative `clamp`). A `clamp` of `0.0` disables the limit.
+    ///
+    /// This keeps a slope-scaled shadow map bias from growing without bound on polygons seen at
+    /// grazing angles, which detaches shadows from their casters ("peter-panning").
+    ///
+    /// Clamping is not supported by core GLES. Where the extension is missing, use
+    /// [`Self::polygon_offset`] with a smaller `factor`, or cull front faces while rendering the
+    /// shadow map.
+    ///
+    /// # Panics
+    /// If the extension entry point was not loaded.
+    ///
+    /// # Safety
+    /// [`Extension::PolygonOffsetClamp`](crate::info::Extension::PolygonOffsetClamp) must be
+    /// [supported](crate::GLHF::supports).
+    #[doc(alias = "glPolygonOffsetClampEXT")]
+    #[doc(alias = "GL_EXT_polygon_offset_clamp")]
+    pub unsafe fn polygon_offset_clamp(&self, factor: f32, units: f32, clamp: f32) -> &Self {
+        unsafe {
+            gl::PolygonOffsetClampEXT(factor, units, clamp);
+        }
+        self
+    }
+    /// Configure a reversed depth buffer, where the near plane is at depth `1.0` and the far plane
+    /// at `0.0`. This sets the [depth range](Self::depth_range) to `1.0..=0.0`, the
+    /// [depth func](Self::depth_func) to [`CompareFunc::Greater`], and the
+    /// [clear depth](Self::clear_depth) to `0.0`.
+    ///
+    /// GLES has no `glClipControl`, so clip-space depth remains in `[-1, 1]` and projection
+    /// matrices need not change - NDC `-1` is mapped to depth `1.0`. For the same reason, much of
+    /// the precision benefit seen on APIs with a `[0, 1]` clip space is lost in the remapping, and
+    /// what remains requires a floating point depth format such as
+    /// [`DepthComponent32f`](crate::texture::InternalFormat::DepthComponent32f).
+    ///
+    /// [`Capability::DepthTest`] must still be enabled separately.
+    #[doc(alias = "glDepthRangef")]
+    #[doc(alias = "glDepthFunc")]
+    #[doc(alias = "glClearDepthf")]
+    pub fn reverse_z(&self) -> &Self {
+        self.depth_range(1.0..=0.0)
+            .depth_func(CompareFunc::Greater)
+            .clear_depth(0.0)
+    }
     #[doc(alias = "glSampleCoverage")]
     pub fn sample_coverage(&self, value: f32, invert: bool) -> &Self {
         unsafe {
@@ -379,6 +1026,46 @@ impl State {
         }
         self
     }
+    /// Get the `(value, invert)` pair last set by [`Self::sample_coverage`]. Initially `(1.0, false)`.
+    ///
+    /// This is not cached and invokes two `glGet`s.
+    #[doc(alias = "glGetFloatv")]
+    #[doc(alias = "glGetBooleanv")]
+    #[doc(alias = "GL_SAMPLE_COVERAGE_VALUE")]
+    #[doc(alias = "GL_SAMPLE_COVERAGE_INVERT")]
+    #[must_use]
+    pub fn get_sample_coverage(&self) -> (f32, bool) {
+        unsafe {
+            let mut value = core::mem::MaybeUninit::uninit();
+            let mut invert = core::mem::MaybeUninit::uninit();
+            gl::GetFloatv(gl::SAMPLE_COVERAGE_VALUE, value.as_mut_ptr());
+            gl::GetBooleanv(gl::SAMPLE_COVERAGE_INVERT, invert.as_mut_ptr());
+            (value.assume_init(), invert.assume_init() == gl::TRUE)
+        }
+    }
+    /// The number of multisample buffers of the framebuffer bound for drawing - `1` if it is
+    /// multisampled, otherwise `0`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_SAMPLE_BUFFERS")]
+    #[must_use]
+    pub fn sample_buffers(&self) -> u32 {
+        get_unsigned(gl::SAMPLE_BUFFERS)
+    }
+    /// The number of samples per pixel of the framebuffer bound for drawing, `0` if it is not
+    /// multisampled.
+    ///
+    /// Where this is nonzero, the framebuffer must be resolved with a blit before its pixels can
+    /// be read.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_SAMPLES")]
+    #[must_use]
+    pub fn samples(&self) -> u32 {
+        get_unsigned(gl::SAMPLES)
+    }
     /// Specify the scissor rectangle for scissor testing, if enabled.
     ///
     /// `min` is the lower-left.
@@ -394,6 +1081,54 @@ impl State {
         }
         self
     }
+    /// Get the rectangle last set by [`Self::scissor`]. Initially the size of the window the
+    /// context was first made current on.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_SCISSOR_BOX")]
+    #[must_use]
+    pub fn get_scissor(&self) -> Rect {
+        get_rect(gl::SCISSOR_BOX)
+    }
+    /// Specify the scissor rectangle for viewport `index`, as [`Self::scissor`] does for
+    /// viewport zero.
+    ///
+    /// # Panics
+    /// If the extension entry point was not loaded.
+    ///
+    /// # Safety
+    /// * [`Extension::ViewportArray`](crate::info::Extension::ViewportArray) must be
+    ///   [supported](crate::GLHF::supports).
+    /// * `index` must be less than `GL_MAX_VIEWPORTS_OES`.
+    #[doc(alias = "glScissorIndexedOES")]
+    #[doc(alias = "GL_OES_viewport_array")]
+    pub unsafe fn scissor_indexed(&self, index: u32, min: [u32; 2], size: [u32; 2]) -> &Self {
+        unsafe {
+            gl::ScissorIndexedOES(
+                index,
+                min[0].try_into().unwrap(),
+                min[1].try_into().unwrap(),
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+            );
+        }
+        self
+    }
+    /// [`Self::scissor`], taking a [`Rect`] which may have a negative origin.
+    #[doc(alias = "glScissor")]
+    pub fn scissor_rect(&self, rect: impl Into<Rect>) -> &Self {
+        let Rect { origin, size } = rect.into();
+        unsafe {
+            gl::Scissor(
+                origin[0],
+                origin[1],
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+            );
+        }
+        self
+    }
     /// Specify the conditions for passing the stencil check.
     ///
     /// For example, if func is [`CompareFunc::GreaterEqual`], the check is
@@ -405,6 +1140,30 @@ impl State {
         }
         self
     }
+    /// Get the `(func, reference, mask)` last set by [`Self::stencil_func`] for `face`. Initially
+    /// `(CompareFunc::Always, 0, !0)`.
+    ///
+    /// This is not cached and invokes three `glGet`s.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_STENCIL_FUNC")]
+    #[doc(alias = "GL_STENCIL_REF")]
+    #[doc(alias = "GL_STENCIL_VALUE_MASK")]
+    #[doc(alias = "GL_STENCIL_BACK_FUNC")]
+    #[doc(alias = "GL_STENCIL_BACK_REF")]
+    #[doc(alias = "GL_STENCIL_BACK_VALUE_MASK")]
+    #[must_use]
+    pub fn get_stencil_func(&self, face: Face) -> (CompareFunc, u32, u32) {
+        let [func, reference, mask] = match face {
+            Face::Front => [gl::STENCIL_FUNC, gl::STENCIL_REF, gl::STENCIL_VALUE_MASK],
+            Face::Back => [
+                gl::STENCIL_BACK_FUNC,
+                gl::STENCIL_BACK_REF,
+                gl::STENCIL_BACK_VALUE_MASK,
+            ],
+        }
+        .map(get_unsigned);
+        (CompareFunc::from_gl(func), reference, mask)
+    }
     /// Specify write-protection of bits within the stencil mask.
     /// Where a 1 appears, the corresponding stencil bit is writable, where a 0 appears,
     /// it is read-only.
@@ -417,6 +1176,19 @@ impl State {
         }
         self
     }
+    /// Get the write mask last set by [`Self::stencil_mask`] for `face`. Initially `!0`.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_STENCIL_WRITEMASK")]
+    #[doc(alias = "GL_STENCIL_BACK_WRITEMASK")]
+    #[must_use]
+    pub fn get_stencil_mask(&self, face: Face) -> u32 {
+        get_unsigned(match face {
+            Face::Front => gl::STENCIL_WRITEMASK,
+            Face::Back => gl::STENCIL_BACK_WRITEMASK,
+        })
+    }
     /// Specify the modifications to make to the stencil buffer when the stencil
     /// test fails, the depth test fails, or neither test fails, respectively.
     #[doc(alias = "glStencilOp")]
@@ -431,6 +1203,34 @@ impl State {
         }
         self
     }
+    /// Get the `(stencil_fail, depth_fail, pass)` operations last set by [`Self::stencil_op`] for
+    /// `face`. Initially all [`StencilOp::Keep`].
+    ///
+    /// This is not cached and invokes three `glGet`s.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_STENCIL_FAIL")]
+    #[doc(alias = "GL_STENCIL_PASS_DEPTH_FAIL")]
+    #[doc(alias = "GL_STENCIL_PASS_DEPTH_PASS")]
+    #[doc(alias = "GL_STENCIL_BACK_FAIL")]
+    #[doc(alias = "GL_STENCIL_BACK_PASS_DEPTH_FAIL")]
+    #[doc(alias = "GL_STENCIL_BACK_PASS_DEPTH_PASS")]
+    #[must_use]
+    pub fn get_stencil_op(&self, face: Face) -> (StencilOp, StencilOp, StencilOp) {
+        let [stencil_fail, depth_fail, pass] = match face {
+            Face::Front => [
+                gl::STENCIL_FAIL,
+                gl::STENCIL_PASS_DEPTH_FAIL,
+                gl::STENCIL_PASS_DEPTH_PASS,
+            ],
+            Face::Back => [
+                gl::STENCIL_BACK_FAIL,
+                gl::STENCIL_BACK_PASS_DEPTH_FAIL,
+                gl::STENCIL_BACK_PASS_DEPTH_PASS,
+            ],
+        }
+        .map(|pname| StencilOp::from_gl(get_unsigned(pname)));
+        (stencil_fail, depth_fail, pass)
+    }
     /// Specifies the transform from NDC space to framebuffer space.
     /// The vertex x and y output ranges of `[-1, 1]` are mapped onto this rectangle.
     ///
@@ -447,4 +1247,81 @@ impl State {
         }
         self
     }
+    /// Get the rectangle last set by [`Self::viewport`]. Initially the size of the window the
+    /// context was first made current on.
+    ///
+    /// This is not cached and invokes a `glGet`.
+    #[doc(alias = "glGetIntegerv")]
+    #[doc(alias = "GL_VIEWPORT")]
+    #[must_use]
+    pub fn get_viewport(&self) -> Rect {
+        get_rect(gl::VIEWPORT)
+    }
+    /// Set the viewport as [`Self::viewport`], with `size` clamped to the implementation's
+    /// `GL_MAX_VIEWPORT_DIMS`, returning the size actually used.
+    ///
+    /// The GL would otherwise clamp the size silently, leaving the NDC-to-framebuffer transform
+    /// different than requested. When rendering a large virtual framebuffer in tiles, the returned
+    /// size can be used to pick the tile size.
+    ///
+    /// This invokes a `glGet` every call.
+    #[doc(alias = "glViewport")]
+    #[doc(alias = "GL_MAX_VIEWPORT_DIMS")]
+    pub fn viewport_clamped(&self, min: [u32; 2], size: [u32; 2]) -> [u32; 2] {
+        let max = unsafe {
+            let mut max = [0; 2];
+            gl::GetIntegerv(gl::MAX_VIEWPORT_DIMS, max.as_mut_ptr());
+            max
+        };
+        let size = [0, 1].map(|axis| size[axis].min(max[axis].try_into().unwrap()));
+        self.viewport(min, size);
+        size
+    }
+    /// Specify the viewport transform for viewport `index`, as [`Self::viewport`] does for
+    /// viewport zero. Primitives are routed to a viewport by writing `gl_ViewportIndex` from a
+    /// geometry shader, allowing each layer of a layered render to have its own transform.
+    ///
+    /// Viewport arrays are not supported by core GLES. Where the extension is missing, render
+    /// each layer in a separate pass with [`Self::viewport`].
+    ///
+    /// # Panics
+    /// If the extension entry point was not loaded.
+    ///
+    /// # Safety
+    /// * [`Extension::ViewportArray`](crate::info::Extension::ViewportArray) must be
+    ///   [supported](crate::GLHF::supports).
+    /// * `index` must be less than `GL_MAX_VIEWPORTS_OES`.
+    #[doc(alias = "glViewportIndexedfOES")]
+    #[doc(alias = "GL_OES_viewport_array")]
+    pub unsafe fn viewport_indexed(&self, index: u32, min: [u32; 2], size: [u32; 2]) -> &Self {
+        unsafe {
+            gl::ViewportIndexedfOES(
+                index,
+                min[0] as f32,
+                min[1] as f32,
+                size[0] as f32,
+                size[1] as f32,
+            );
+        }
+        self
+    }
+    /// [`Self::viewport`], taking a [`Rect`] which may have a negative origin.
+    ///
+    /// ```no_run
+    /// # let gl : glhf::GLHF = todo!();
+    /// gl.state.viewport_rect((0, 0, 1920, 1080));
+    /// ```
+    #[doc(alias = "glViewport")]
+    pub fn viewport_rect(&self, rect: impl Into<Rect>) -> &Self {
+        let Rect { origin, size } = rect.into();
+        unsafe {
+            gl::Viewport(
+                origin[0],
+                origin[1],
+                size[0].try_into().unwrap(),
+                size[1].try_into().unwrap(),
+            );
+        }
+        self
+    }
 }
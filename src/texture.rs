@@ -32,33 +32,48 @@ enum Dimensionality {
 }*/
 
 /// # Safety
-/// TARGET must be one of `GL_TEXTURE_{2D, 3D, 2D_ARRAY, CUBE_MAP}`
+/// TARGET must be one of `GL_TEXTURE_{2D, 3D, 2D_ARRAY, CUBE_MAP, 2D_MULTISAMPLE}`
 pub unsafe trait Dimensionality: crate::sealed::Sealed {
     const TARGET: GLenum;
 }
+/// Dimensionalities which are sampled with filtering, wrapping, and comparison state -
+/// every dimensionality but [`D2Multisample`].
+pub trait Filterable: Dimensionality {}
 pub struct D2;
 impl crate::sealed::Sealed for D2 {}
 unsafe impl Dimensionality for D2 {
     const TARGET: GLenum = gl::TEXTURE_2D;
 }
+impl Filterable for D2 {}
 pub struct D3;
 impl crate::sealed::Sealed for D3 {}
 unsafe impl Dimensionality for D3 {
     const TARGET: GLenum = gl::TEXTURE_3D;
 }
+impl Filterable for D3 {}
 pub struct D2Array;
 impl crate::sealed::Sealed for D2Array {}
 unsafe impl Dimensionality for D2Array {
     const TARGET: GLenum = gl::TEXTURE_2D_ARRAY;
 }
+impl Filterable for D2Array {}
 pub struct Cube;
 impl crate::sealed::Sealed for Cube {}
 unsafe impl Dimensionality for Cube {
     const TARGET: GLenum = gl::TEXTURE_CUBE_MAP;
 }
+impl Filterable for Cube {}
+/// A single-level 2D image with several samples per texel, read in shaders by `texelFetch`.
+///
+/// Requires GLES 3.1.
+pub struct D2Multisample;
+impl crate::sealed::Sealed for D2Multisample {}
+unsafe impl Dimensionality for D2Multisample {
+    const TARGET: GLenum = gl::TEXTURE_2D_MULTISAMPLE;
+}
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum InternalFormat {
     // Unsized color formats, i.e. the GL is allowed to chose any size it pleases.
     RGB = gl::RGB,
@@ -118,6 +133,29 @@ pub enum InternalFormat {
     Rgba32i = gl::RGBA32I,
     Rgba32ui = gl::RGBA32UI,
 
+    // Sized color formats from extensions. Using these where the extension is not supported
+    // generates `GL_INVALID_ENUM`.
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    R16 = gl::R16_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    R16Snorm = gl::R16_SNORM_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    Rg16 = gl::RG16_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    Rg16Snorm = gl::RG16_SNORM_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    Rgb16 = gl::RGB16_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    Rgb16Snorm = gl::RGB16_SNORM_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    Rgba16 = gl::RGBA16_EXT,
+    /// Requires [`Extension::TextureNorm16`](crate::info::Extension::TextureNorm16).
+    Rgba16Snorm = gl::RGBA16_SNORM_EXT,
+    /// Requires [`Extension::TextureSrgbR8`](crate::info::Extension::TextureSrgbR8).
+    Sr8 = gl::SR8_EXT,
+    /// Requires [`Extension::TextureSrgbRg8`](crate::info::Extension::TextureSrgbRg8).
+    Srg8 = gl::SRG8_EXT,
+
     // Sized depth / stencil
     DepthComponent16 = gl::DEPTH_COMPONENT16,
     DepthComponent24 = gl::DEPTH_COMPONENT24,
@@ -128,6 +166,81 @@ pub enum InternalFormat {
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for InternalFormat {}
 impl InternalFormat {
+    /// Decode an internal format reported by the GL, `None` if it is not one this crate models.
+    pub(crate) fn from_gl(value: GLenum) -> Option<Self> {
+        Some(match value {
+            gl::RGB => Self::RGB,
+            gl::RGBA => Self::RGBA,
+            gl::LUMINANCE_ALPHA => Self::LuminanceAlpha,
+            gl::LUMINANCE => Self::Luminance,
+            gl::ALPHA => Self::Alpha,
+            gl::R8 => Self::R8,
+            gl::R8_SNORM => Self::R8Snorm,
+            gl::R16F => Self::R16f,
+            gl::R32F => Self::R32f,
+            gl::R8UI => Self::R8ui,
+            gl::R8I => Self::R8i,
+            gl::R16UI => Self::R16ui,
+            gl::R16I => Self::R16i,
+            gl::R32UI => Self::R32ui,
+            gl::R32I => Self::R32i,
+            gl::RG8 => Self::Rg8,
+            gl::RG8_SNORM => Self::Rg8Snorm,
+            gl::RG16F => Self::Rg16f,
+            gl::RG32F => Self::Rg32f,
+            gl::RG8UI => Self::Rg8ui,
+            gl::RG8I => Self::Rg8i,
+            gl::RG16UI => Self::Rg16ui,
+            gl::RG16I => Self::Rg16i,
+            gl::RG32UI => Self::Rg32ui,
+            gl::RG32I => Self::Rg32i,
+            gl::RGB8 => Self::Rgb8,
+            gl::SRGB8 => Self::Srgb8,
+            gl::RGB565 => Self::Rgb565,
+            gl::RGB8_SNORM => Self::Rgb8Snorm,
+            gl::R11F_G11F_B10F => Self::R11fG11fB10f,
+            gl::RGB9_E5 => Self::Rgb9E5,
+            gl::RGB16F => Self::Rgb16f,
+            gl::RGB32F => Self::Rgb32f,
+            gl::RGB8UI => Self::Rgb8ui,
+            gl::RGB8I => Self::Rgb8i,
+            gl::RGB16UI => Self::Rgb16ui,
+            gl::RGB16I => Self::Rgb16i,
+            gl::RGB32UI => Self::Rgb32ui,
+            gl::RGB32I => Self::Rgb32i,
+            gl::RGBA8 => Self::Rgba8,
+            gl::SRGB8_ALPHA8 => Self::Srgb8Alpha8,
+            gl::RGBA8_SNORM => Self::Rgba8Snorm,
+            gl::RGB5_A1 => Self::Rgb5A1,
+            gl::RGBA4 => Self::Rgba4,
+            gl::RGB10_A2 => Self::Rgb10A2,
+            gl::RGBA16F => Self::Rgba16f,
+            gl::RGBA32F => Self::Rgba32f,
+            gl::RGBA8UI => Self::Rgba8ui,
+            gl::RGBA8I => Self::Rgba8i,
+            gl::RGB10_A2UI => Self::Rgb10A2ui,
+            gl::RGBA16UI => Self::Rgba16ui,
+            gl::RGBA16I => Self::Rgba16i,
+            gl::RGBA32I => Self::Rgba32i,
+            gl::RGBA32UI => Self::Rgba32ui,
+            gl::R16_EXT => Self::R16,
+            gl::R16_SNORM_EXT => Self::R16Snorm,
+            gl::RG16_EXT => Self::Rg16,
+            gl::RG16_SNORM_EXT => Self::Rg16Snorm,
+            gl::RGB16_EXT => Self::Rgb16,
+            gl::RGB16_SNORM_EXT => Self::Rgb16Snorm,
+            gl::RGBA16_EXT => Self::Rgba16,
+            gl::RGBA16_SNORM_EXT => Self::Rgba16Snorm,
+            gl::SR8_EXT => Self::Sr8,
+            gl::SRG8_EXT => Self::Srg8,
+            gl::DEPTH_COMPONENT16 => Self::DepthComponent16,
+            gl::DEPTH_COMPONENT24 => Self::DepthComponent24,
+            gl::DEPTH_COMPONENT32F => Self::DepthComponent32f,
+            gl::DEPTH24_STENCIL8 => Self::Depth24Stencil8,
+            gl::DEPTH32F_STENCIL8 => Self::Depth32fStencil8,
+            _ => return None,
+        })
+    }
     /// Get the "format" `GLenum` associated with this internal format.
     /// This describes the layout of pixel data in a buffer.
     ///
@@ -194,6 +307,17 @@ impl InternalFormat {
             Self::Rgba32i => Format::RGBAInteger,
             Self::Rgba32ui => Format::RGBAInteger,
 
+            Self::R16 => Format::Red,
+            Self::R16Snorm => Format::Red,
+            Self::Rg16 => Format::RG,
+            Self::Rg16Snorm => Format::RG,
+            Self::Rgb16 => Format::RGB,
+            Self::Rgb16Snorm => Format::RGB,
+            Self::Rgba16 => Format::RGBA,
+            Self::Rgba16Snorm => Format::RGBA,
+            Self::Sr8 => Format::Red,
+            Self::Srg8 => Format::RG,
+
             Self::DepthComponent16 => Format::DepthComponent,
             Self::DepthComponent24 => Format::DepthComponent,
             Self::DepthComponent32f => Format::DepthComponent,
@@ -201,10 +325,137 @@ impl InternalFormat {
             Self::Depth32fStencil8 => Format::DepthStencil,
         }
     }
+    /// Check that pixel data described by `format` and `ty` may be transferred to or from an image
+    /// of this internal format.
+    ///
+    /// The GL admits exactly one `format` for each internal format, and a small set of types.
+    /// Anything else is a `GL_INVALID_OPERATION`.
+    ///
+    /// ```
+    /// # use glhf::texture::{DataType, Format, InternalFormat};
+    /// assert!(InternalFormat::Rgba8.validate_transfer(Format::RGBA, DataType::U8).is_ok());
+    /// // Integer formats need an `*Integer` format.
+    /// assert!(InternalFormat::Rgba8ui.validate_transfer(Format::RGBA, DataType::U8).is_err());
+    /// assert!(InternalFormat::R32f.validate_transfer(Format::Red, DataType::U8).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// If `format` is not [`Self::format`], or `ty` is not
+    /// [compatible](DataType::compatible_with_internal_format).
+    pub fn validate_transfer(&self, format: Format, ty: DataType) -> Result<(), FormatMismatch> {
+        let expected = self.format();
+        if format != expected {
+            Err(FormatMismatch::Format {
+                internal_format: *self,
+                expected,
+                actual: format,
+            })
+        } else if !ty.compatible_with_internal_format(*self) {
+            Err(FormatMismatch::DataType {
+                internal_format: *self,
+                actual: ty,
+            })
+        } else {
+            Ok(())
+        }
+    }
+    /// The number of channels stored, including depth and stencil.
+    #[must_use]
+    pub fn channel_count(&self) -> usize {
+        self.format().components()
+    }
+    /// Whether this format holds color data, as opposed to depth and/or stencil.
+    #[must_use]
+    pub fn is_color(&self) -> bool {
+        !self.is_depth() && !self.is_stencil()
+    }
+    /// Whether this format holds unnormalized integers, which may only be sampled with
+    /// [`Filter::Nearest`].
+    #[must_use]
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self.format(),
+            Format::RedInteger | Format::RGInteger | Format::RGBInteger | Format::RGBAInteger
+        )
+    }
+    /// Whether this format has a depth channel. Combined depth-stencil formats do.
+    #[must_use]
+    pub fn is_depth(&self) -> bool {
+        matches!(self.format(), Format::DepthComponent | Format::DepthStencil)
+    }
+    /// Whether this format has a stencil channel.
+    #[must_use]
+    pub fn is_stencil(&self) -> bool {
+        matches!(self.format(), Format::DepthStencil | Format::Stencil)
+    }
+    /// Whether this format has an alpha channel.
+    #[must_use]
+    pub fn has_alpha(&self) -> bool {
+        matches!(
+            self.format(),
+            Format::Alpha | Format::LuminanceAlpha | Format::RGBA | Format::RGBAInteger
+        )
+    }
+    /// The nominal size of a texel, in bytes, rounded up. `None` for unsized formats, for which the
+    /// GL chooses a size.
+    ///
+    /// Implementations are free to store texels with more bits than asked for, so this is a
+    /// lower bound on memory use. It is *not* the size of a pixel transfer, see [`DataType`] for that.
+    ///
+    /// ```
+    /// # use glhf::texture::InternalFormat;
+    /// assert_eq!(InternalFormat::Rgba8.bytes_per_texel(), Some(4));
+    /// assert_eq!(InternalFormat::Depth32fStencil8.bytes_per_texel(), Some(5));
+    /// assert_eq!(InternalFormat::RGBA.bytes_per_texel(), None);
+    /// ```
+    #[must_use]
+    pub fn bytes_per_texel(&self) -> Option<usize> {
+        Some(match self {
+            Self::RGB | Self::RGBA | Self::LuminanceAlpha | Self::Luminance | Self::Alpha => {
+                return None
+            }
+
+            Self::R8 | Self::R8Snorm | Self::R8ui | Self::R8i => 1,
+            Self::R16f | Self::R16ui | Self::R16i => 2,
+            Self::R32f | Self::R32ui | Self::R32i => 4,
+
+            Self::Rg8 | Self::Rg8Snorm | Self::Rg8ui | Self::Rg8i => 2,
+            Self::Rg16f | Self::Rg16ui | Self::Rg16i => 4,
+            Self::Rg32f | Self::Rg32ui | Self::Rg32i => 8,
+
+            Self::Rgb565 => 2,
+            Self::Rgb8 | Self::Srgb8 | Self::Rgb8Snorm | Self::Rgb8ui | Self::Rgb8i => 3,
+            Self::R11fG11fB10f | Self::Rgb9E5 => 4,
+            Self::Rgb16f | Self::Rgb16ui | Self::Rgb16i => 6,
+            Self::Rgb32f | Self::Rgb32ui | Self::Rgb32i => 12,
+
+            Self::Rgb5A1 | Self::Rgba4 => 2,
+            Self::Rgba8
+            | Self::Srgb8Alpha8
+            | Self::Rgba8Snorm
+            | Self::Rgba8ui
+            | Self::Rgba8i
+            | Self::Rgb10A2
+            | Self::Rgb10A2ui => 4,
+            Self::Rgba16f | Self::Rgba16ui | Self::Rgba16i => 8,
+            Self::Rgba32f | Self::Rgba32i | Self::Rgba32ui => 16,
+
+            Self::Sr8 => 1,
+            Self::R16 | Self::R16Snorm | Self::Srg8 => 2,
+            Self::Rg16 | Self::Rg16Snorm => 4,
+            Self::Rgb16 | Self::Rgb16Snorm => 6,
+            Self::Rgba16 | Self::Rgba16Snorm => 8,
+
+            Self::DepthComponent16 => 2,
+            Self::DepthComponent24 => 3,
+            Self::DepthComponent32f | Self::Depth24Stencil8 => 4,
+            Self::Depth32fStencil8 => 5,
+        })
+    }
 }
 
 #[repr(u32)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Format {
     Alpha = gl::ALPHA,
     Luminance = gl::LUMINANCE,
@@ -225,6 +476,21 @@ pub enum Format {
 }
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for Format {}
+impl Format {
+    /// The number of components in each pixel of this format.
+    ///
+    /// For [packed](DataType::is_packed) data types, all components of a pixel share a single element.
+    #[must_use]
+    pub fn components(&self) -> usize {
+        match self {
+            Self::Alpha | Self::Luminance | Self::Red | Self::RedInteger => 1,
+            Self::DepthComponent | Self::Stencil => 1,
+            Self::LuminanceAlpha | Self::RG | Self::RGInteger | Self::DepthStencil => 2,
+            Self::RGB | Self::RGBInteger => 3,
+            Self::RGBA | Self::RGBAInteger => 4,
+        }
+    }
+}
 
 #[repr(u32)]
 #[derive(Copy, Clone)]
@@ -247,6 +513,52 @@ pub enum ImageData<'data> {
     F32Reverse24_8(&'data [F32Reverse24_8]) = gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
 }
 
+/// Some [`ImageData`] was too short for the image it was to describe.
+#[derive(Debug)]
+pub struct SizeMismatch {
+    /// The number of elements needed for the image.
+    pub expected: usize,
+    /// The number of elements provided.
+    pub actual: usize,
+}
+
+/// The [`Format`] or [`DataType`] of a pixel transfer does not pair with the [`InternalFormat`] of
+/// the image, see [`InternalFormat::validate_transfer`].
+#[derive(Debug)]
+pub enum FormatMismatch {
+    /// The format differs from [`InternalFormat::format`].
+    Format {
+        internal_format: InternalFormat,
+        expected: Format,
+        actual: Format,
+    },
+    /// The data type is not accepted by the internal format, see
+    /// [`DataType::compatible_with_internal_format`].
+    DataType {
+        internal_format: InternalFormat,
+        actual: DataType,
+    },
+}
+
+/// A pixel transfer rejected by [`slot::texture::Active::try_sub_image`](crate::slot::texture::Active::try_sub_image).
+#[derive(Debug)]
+pub enum TransferError {
+    /// The data was too short for the region.
+    Size(SizeMismatch),
+    /// The format or data type does not pair with the texture's internal format.
+    Format(FormatMismatch),
+}
+impl From<SizeMismatch> for TransferError {
+    fn from(value: SizeMismatch) -> Self {
+        Self::Size(value)
+    }
+}
+impl From<FormatMismatch> for TransferError {
+    fn from(value: FormatMismatch) -> Self {
+        Self::Format(value)
+    }
+}
+
 // A unique type is needed here (as opposed to u64) because the GL treats this as two individual values, so the
 // endian would be all messed up if u64 was used.
 #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
@@ -262,85 +574,364 @@ pub struct F32Reverse24_8 {
 unsafe impl crate::GLEnum for ImageData<'_> {}
 
 impl ImageData<'_> {
+    /// Get the datatype of the elements, discarding the data.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::U8(_) => DataType::U8,
+            Self::I8(_) => DataType::I8,
+            Self::U16(_) => DataType::U16,
+            Self::I16(_) => DataType::I16,
+            Self::U32(_) => DataType::U32,
+            Self::I32(_) => DataType::I32,
+            Self::F16(_) => DataType::F16,
+            Self::F32(_) => DataType::F32,
+            Self::Packed5_6_5(_) => DataType::Packed5_6_5,
+            Self::Packed4_4_4_4(_) => DataType::Packed4_4_4_4,
+            Self::Packed5_5_5_1(_) => DataType::Packed5_5_5_1,
+            Self::Reverse2_10_10_10(_) => DataType::Reverse2_10_10_10,
+            Self::Reverse10F11F11F(_) => DataType::Reverse10F11F11F,
+            Self::Reverse5_9_9_9(_) => DataType::Reverse5_9_9_9,
+            Self::Packed24_8(_) => DataType::Packed24_8,
+            Self::F32Reverse24_8(_) => DataType::F32Reverse24_8,
+        }
+    }
+    /// Get the number of elements in the data. For [packed](DataType::is_packed) data types, each
+    /// element holds every component of a pixel.
+    #[must_use]
+    pub fn element_count(&self) -> usize {
+        let (_, len) = self.as_bytes();
+        len / self.data_type().size_of()
+    }
+    /// Check that the data holds at least an image of the given size in `format`, including
+    /// row padding to a multiple of four bytes (the default `GL_UNPACK_ALIGNMENT`). Any elements
+    /// beyond that are ignored by the transfer.
+    ///
+    /// ```
+    /// # use glhf::texture::{Format, ImageData};
+    /// // Rows of 3 RGB bytes are padded from 9 to 12 bytes, except the last.
+    /// assert!(ImageData::U8(&[0; 21]).validate_for(Format::RGB, 3, 2, 1).is_ok());
+    /// let err = ImageData::U8(&[0; 20]).validate_for(Format::RGB, 3, 2, 1).unwrap_err();
+    /// assert_eq!((err.expected, err.actual), (21, 20));
+    /// // Excess elements are fine.
+    /// assert!(ImageData::U8(&[0; 64]).validate_for(Format::RGB, 3, 2, 1).is_ok());
+    /// // A packed element holds a whole pixel.
+    /// assert!(ImageData::Packed5_6_5(&[0; 4]).validate_for(Format::RGB, 2, 2, 1).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    /// If there are fewer elements than needed for the image.
+    pub fn validate_for(
+        &self,
+        format: Format,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Result<(), SizeMismatch> {
+        let ty = self.data_type();
+        let expected = ty
+            .image_len(format, [width, height, depth])
+            .div_ceil(ty.size_of());
+        let actual = self.element_count();
+        if actual >= expected {
+            Ok(())
+        } else {
+            Err(SizeMismatch { expected, actual })
+        }
+    }
+    /// Get a pointer to the beginning of the data, and it's length in bytes.
+    pub(crate) fn as_bytes(&self) -> (*const core::ffi::c_void, usize) {
+        fn raw<T>(slice: &[T]) -> (*const core::ffi::c_void, usize) {
+            (slice.as_ptr().cast(), core::mem::size_of_val(slice))
+        }
+        match self {
+            Self::U8(s) => raw(s),
+            Self::I8(s) => raw(s),
+            Self::U16(s)
+            | Self::F16(s)
+            | Self::Packed5_6_5(s)
+            | Self::Packed4_4_4_4(s)
+            | Self::Packed5_5_5_1(s) => raw(s),
+            Self::I16(s) => raw(s),
+            Self::U32(s)
+            | Self::Reverse2_10_10_10(s)
+            | Self::Reverse10F11F11F(s)
+            | Self::Reverse5_9_9_9(s)
+            | Self::Packed24_8(s) => raw(s),
+            Self::I32(s) => raw(s),
+            Self::F32(s) => raw(s),
+            Self::F32Reverse24_8(s) => raw(s),
+        }
+    }
+    /// Whether this data may be uploaded to an image of the given internal format, see
+    /// [`DataType::compatible_with_internal_format`].
+    #[must_use]
+    pub fn compatible_with_internal_format(&self, format: InternalFormat) -> bool {
+        self.data_type().compatible_with_internal_format(format)
+    }
+}
+
+/// The datatype of pixel data in host memory or in a pixel buffer. This is the [`ImageData`]
+/// discriminant, without the data.
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DataType {
+    U8 = gl::UNSIGNED_BYTE,
+    I8 = gl::BYTE,
+    U16 = gl::UNSIGNED_SHORT,
+    I16 = gl::SHORT,
+    U32 = gl::UNSIGNED_INT,
+    I32 = gl::INT,
+    F16 = gl::HALF_FLOAT,
+    F32 = gl::FLOAT,
+    Packed5_6_5 = gl::UNSIGNED_SHORT_5_6_5,
+    Packed4_4_4_4 = gl::UNSIGNED_SHORT_4_4_4_4,
+    Packed5_5_5_1 = gl::UNSIGNED_SHORT_5_5_5_1,
+    Reverse2_10_10_10 = gl::UNSIGNED_INT_2_10_10_10_REV,
+    Reverse10F11F11F = gl::UNSIGNED_INT_10F_11F_11F_REV,
+    Reverse5_9_9_9 = gl::UNSIGNED_INT_5_9_9_9_REV,
+    Packed24_8 = gl::UNSIGNED_INT_24_8,
+    F32Reverse24_8 = gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for DataType {}
+impl DataType {
+    /// The size, in bytes, of a single element of this type.
+    #[must_use]
+    pub fn size_of(&self) -> usize {
+        match self {
+            Self::U8 | Self::I8 => 1,
+            Self::U16 | Self::I16 | Self::F16 => 2,
+            Self::Packed5_6_5 | Self::Packed4_4_4_4 | Self::Packed5_5_5_1 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::Reverse2_10_10_10
+            | Self::Reverse10F11F11F
+            | Self::Reverse5_9_9_9
+            | Self::Packed24_8 => 4,
+            Self::F32Reverse24_8 => core::mem::size_of::<F32Reverse24_8>(),
+        }
+    }
+    /// Whether a single element of this type holds every component of a pixel.
+    #[must_use]
+    pub fn is_packed(&self) -> bool {
+        !matches!(
+            self,
+            Self::U8
+                | Self::I8
+                | Self::U16
+                | Self::I16
+                | Self::U32
+                | Self::I32
+                | Self::F16
+                | Self::F32
+        )
+    }
+    /// Whether pixel data of this type may be transferred to or from an image of the given internal
+    /// format.
     #[must_use]
     pub fn compatible_with_internal_format(&self, format: InternalFormat) -> bool {
         // Implement big table seen at https://registry.khronos.org/OpenGL-Refpages/es3.0/
         match format {
-            InternalFormat::RGB => matches!(self, Self::U8(_) | Self::Packed5_6_5(_)),
-            InternalFormat::RGBA => matches!(
-                self,
-                Self::U8(_) | Self::Packed4_4_4_4(_) | Self::Packed5_5_5_1(_)
-            ),
-            InternalFormat::LuminanceAlpha => matches!(self, Self::U8(_)),
-            InternalFormat::Luminance => matches!(self, Self::U8(_)),
-            InternalFormat::Alpha => matches!(self, Self::U8(_)),
-
-            InternalFormat::R8 => matches!(self, Self::U8(_)),
-            InternalFormat::R8Snorm => matches!(self, Self::I8(_)),
-            InternalFormat::R16f => matches!(self, Self::F16(_) | Self::F32(_)),
-            InternalFormat::R32f => matches!(self, Self::F32(_)),
-            InternalFormat::R8ui => matches!(self, Self::U8(_)),
-            InternalFormat::R8i => matches!(self, Self::I8(_)),
-            InternalFormat::R16ui => matches!(self, Self::U16(_)),
-            InternalFormat::R16i => matches!(self, Self::I16(_)),
-            InternalFormat::R32ui => matches!(self, Self::U32(_)),
-            InternalFormat::R32i => matches!(self, Self::I32(_)),
-
-            InternalFormat::Rg8 => matches!(self, Self::U8(_)),
-            InternalFormat::Rg8Snorm => matches!(self, Self::I8(_)),
-            InternalFormat::Rg16f => matches!(self, Self::F16(_) | Self::F32(_)),
-            InternalFormat::Rg32f => matches!(self, Self::F32(_)),
-            InternalFormat::Rg8ui => matches!(self, Self::U8(_)),
-            InternalFormat::Rg8i => matches!(self, Self::I8(_)),
-            InternalFormat::Rg16ui => matches!(self, Self::U16(_)),
-            InternalFormat::Rg16i => matches!(self, Self::I16(_)),
-            InternalFormat::Rg32ui => matches!(self, Self::U32(_)),
-            InternalFormat::Rg32i => matches!(self, Self::I32(_)),
-
-            InternalFormat::Rgb8 => matches!(self, Self::U8(_)),
-            InternalFormat::Srgb8 => matches!(self, Self::U8(_)),
-            InternalFormat::Rgb565 => matches!(self, Self::U8(_) | Self::Packed5_6_5(_)),
-            InternalFormat::Rgb8Snorm => matches!(self, Self::I8(_)),
-            InternalFormat::R11fG11fB10f => matches!(
-                self,
-                Self::F16(_) | Self::F32(_) | Self::Reverse10F11F11F(_)
-            ),
+            InternalFormat::RGB => matches!(self, Self::U8 | Self::Packed5_6_5),
+            InternalFormat::RGBA => {
+                matches!(self, Self::U8 | Self::Packed4_4_4_4 | Self::Packed5_5_5_1)
+            }
+            InternalFormat::LuminanceAlpha => matches!(self, Self::U8),
+            InternalFormat::Luminance => matches!(self, Self::U8),
+            InternalFormat::Alpha => matches!(self, Self::U8),
+
+            InternalFormat::R8 => matches!(self, Self::U8),
+            InternalFormat::R8Snorm => matches!(self, Self::I8),
+            InternalFormat::R16f => matches!(self, Self::F16 | Self::F32),
+            InternalFormat::R32f => matches!(self, Self::F32),
+            InternalFormat::R8ui => matches!(self, Self::U8),
+            InternalFormat::R8i => matches!(self, Self::I8),
+            InternalFormat::R16ui => matches!(self, Self::U16),
+            InternalFormat::R16i => matches!(self, Self::I16),
+            InternalFormat::R32ui => matches!(self, Self::U32),
+            InternalFormat::R32i => matches!(self, Self::I32),
+
+            InternalFormat::Rg8 => matches!(self, Self::U8),
+            InternalFormat::Rg8Snorm => matches!(self, Self::I8),
+            InternalFormat::Rg16f => matches!(self, Self::F16 | Self::F32),
+            InternalFormat::Rg32f => matches!(self, Self::F32),
+            InternalFormat::Rg8ui => matches!(self, Self::U8),
+            InternalFormat::Rg8i => matches!(self, Self::I8),
+            InternalFormat::Rg16ui => matches!(self, Self::U16),
+            InternalFormat::Rg16i => matches!(self, Self::I16),
+            InternalFormat::Rg32ui => matches!(self, Self::U32),
+            InternalFormat::Rg32i => matches!(self, Self::I32),
+
+            InternalFormat::Rgb8 => matches!(self, Self::U8),
+            InternalFormat::Srgb8 => matches!(self, Self::U8),
+            InternalFormat::Rgb565 => matches!(self, Self::U8 | Self::Packed5_6_5),
+            InternalFormat::Rgb8Snorm => matches!(self, Self::I8),
+            InternalFormat::R11fG11fB10f => {
+                matches!(self, Self::F16 | Self::F32 | Self::Reverse10F11F11F)
+            }
             InternalFormat::Rgb9E5 => {
-                matches!(self, Self::F16(_) | Self::F32(_) | Self::Reverse5_9_9_9(_))
+                matches!(self, Self::F16 | Self::F32 | Self::Reverse5_9_9_9)
             }
-            InternalFormat::Rgb16f => matches!(self, Self::F16(_) | Self::F32(_)),
-            InternalFormat::Rgb32f => matches!(self, Self::F32(_)),
-            InternalFormat::Rgb8ui => matches!(self, Self::U8(_)),
-            InternalFormat::Rgb8i => matches!(self, Self::I8(_)),
-            InternalFormat::Rgb16ui => matches!(self, Self::U16(_)),
-            InternalFormat::Rgb16i => matches!(self, Self::I16(_)),
-            InternalFormat::Rgb32ui => matches!(self, Self::U32(_)),
-            InternalFormat::Rgb32i => matches!(self, Self::I32(_)),
-
-            InternalFormat::Rgba8 => matches!(self, Self::U8(_)),
-            InternalFormat::Srgb8Alpha8 => matches!(self, Self::U8(_)),
-            InternalFormat::Rgba8Snorm => matches!(self, Self::I8(_)),
+            InternalFormat::Rgb16f => matches!(self, Self::F16 | Self::F32),
+            InternalFormat::Rgb32f => matches!(self, Self::F32),
+            InternalFormat::Rgb8ui => matches!(self, Self::U8),
+            InternalFormat::Rgb8i => matches!(self, Self::I8),
+            InternalFormat::Rgb16ui => matches!(self, Self::U16),
+            InternalFormat::Rgb16i => matches!(self, Self::I16),
+            InternalFormat::Rgb32ui => matches!(self, Self::U32),
+            InternalFormat::Rgb32i => matches!(self, Self::I32),
+
+            InternalFormat::Rgba8 => matches!(self, Self::U8),
+            InternalFormat::Srgb8Alpha8 => matches!(self, Self::U8),
+            InternalFormat::Rgba8Snorm => matches!(self, Self::I8),
             InternalFormat::Rgb5A1 => matches!(
                 self,
-                Self::U8(_) | Self::Packed5_5_5_1(_) | Self::Reverse2_10_10_10(_)
+                Self::U8 | Self::Packed5_5_5_1 | Self::Reverse2_10_10_10
             ),
-            InternalFormat::Rgba4 => matches!(self, Self::U8(_) | Self::Packed4_4_4_4(_)),
-            InternalFormat::Rgb10A2 => matches!(self, Self::Reverse2_10_10_10(_)),
-            InternalFormat::Rgba16f => matches!(self, Self::F16(_) | Self::F32(_)),
-            InternalFormat::Rgba32f => matches!(self, Self::F32(_)),
-            InternalFormat::Rgba8ui => matches!(self, Self::U8(_)),
-            InternalFormat::Rgba8i => matches!(self, Self::I8(_)),
-            InternalFormat::Rgb10A2ui => matches!(self, Self::Reverse2_10_10_10(_)),
-            InternalFormat::Rgba16ui => matches!(self, Self::U16(_)),
-            InternalFormat::Rgba16i => matches!(self, Self::I16(_)),
-            InternalFormat::Rgba32i => matches!(self, Self::I32(_)),
-            InternalFormat::Rgba32ui => matches!(self, Self::U32(_)),
-
-            InternalFormat::DepthComponent16 => matches!(self, Self::U16(_) | Self::U32(_)),
-            InternalFormat::DepthComponent24 => matches!(self, Self::U32(_)),
-            InternalFormat::DepthComponent32f => matches!(self, Self::F32(_)),
-            InternalFormat::Depth24Stencil8 => matches!(self, Self::Packed24_8(_)),
-            InternalFormat::Depth32fStencil8 => matches!(self, Self::F32Reverse24_8(_)),
+            InternalFormat::Rgba4 => matches!(self, Self::U8 | Self::Packed4_4_4_4),
+            InternalFormat::Rgb10A2 => matches!(self, Self::Reverse2_10_10_10),
+            InternalFormat::Rgba16f => matches!(self, Self::F16 | Self::F32),
+            InternalFormat::Rgba32f => matches!(self, Self::F32),
+            InternalFormat::Rgba8ui => matches!(self, Self::U8),
+            InternalFormat::Rgba8i => matches!(self, Self::I8),
+            InternalFormat::Rgb10A2ui => matches!(self, Self::Reverse2_10_10_10),
+            InternalFormat::Rgba16ui => matches!(self, Self::U16),
+            InternalFormat::Rgba16i => matches!(self, Self::I16),
+            InternalFormat::Rgba32i => matches!(self, Self::I32),
+            InternalFormat::Rgba32ui => matches!(self, Self::U32),
+
+            // From the respective extension specs.
+            InternalFormat::R16 => matches!(self, Self::U16),
+            InternalFormat::R16Snorm => matches!(self, Self::I16),
+            InternalFormat::Rg16 => matches!(self, Self::U16),
+            InternalFormat::Rg16Snorm => matches!(self, Self::I16),
+            InternalFormat::Rgb16 => matches!(self, Self::U16),
+            InternalFormat::Rgb16Snorm => matches!(self, Self::I16),
+            InternalFormat::Rgba16 => matches!(self, Self::U16),
+            InternalFormat::Rgba16Snorm => matches!(self, Self::I16),
+            InternalFormat::Sr8 => matches!(self, Self::U8),
+            InternalFormat::Srg8 => matches!(self, Self::U8),
+
+            InternalFormat::DepthComponent16 => matches!(self, Self::U16 | Self::U32),
+            InternalFormat::DepthComponent24 => matches!(self, Self::U32),
+            InternalFormat::DepthComponent32f => matches!(self, Self::F32),
+            InternalFormat::Depth24Stencil8 => matches!(self, Self::Packed24_8),
+            InternalFormat::Depth32fStencil8 => matches!(self, Self::F32Reverse24_8),
+        }
+    }
+    /// The number of bytes occupied by a `size` region of pixels of the given `format`, including
+    /// row padding.
+    ///
+    /// GL pads each row to `GL_{UN}PACK_ALIGNMENT` bytes. This crate never changes the alignment,
+    /// so the default of `4` is assumed. The final row is not padded.
+    ///
+    /// ```
+    /// # use glhf::texture::{DataType, Format};
+    /// // Two rows of 9 bytes, the first padded to 12.
+    /// assert_eq!(DataType::U8.image_len(Format::RGB, [3, 2, 1]), 21);
+    /// // Rows that are already aligned need no padding.
+    /// assert_eq!(DataType::F32.image_len(Format::RGB, [3, 2, 1]), 72);
+    /// // A packed element holds a whole pixel, so rows are 6 bytes, padded to 8.
+    /// assert_eq!(DataType::Packed5_6_5.image_len(Format::RGB, [3, 2, 1]), 14);
+    /// assert_eq!(DataType::U8.image_len(Format::RGBA, [4, 0, 1]), 0);
+    /// ```
+    #[must_use]
+    pub fn image_len(&self, format: Format, size: [u32; 3]) -> usize {
+        const ALIGNMENT: usize = 4;
+
+        let [width, height, depth] = size.map(|n| usize::try_from(n).unwrap());
+        if width == 0 || height == 0 || depth == 0 {
+            return 0;
+        }
+        let elements_per_pixel = if self.is_packed() {
+            1
+        } else {
+            format.components()
+        };
+        let row = width
+            .checked_mul(elements_per_pixel)
+            .and_then(|n| n.checked_mul(self.size_of()))
+            .unwrap();
+        let row_stride = row.next_multiple_of(ALIGNMENT);
+        let rows = height.checked_mul(depth).unwrap();
+
+        row_stride
+            .checked_mul(rows - 1)
+            .unwrap()
+            .checked_add(row)
+            .unwrap()
+    }
+}
+
+/// Destination for pixel data downloaded from the GL, the mutable counterpart of [`ImageData`].
+#[repr(u32)]
+pub enum ImageDataMut<'data> {
+    U8(&'data mut [u8]) = gl::UNSIGNED_BYTE,
+    I8(&'data mut [i8]) = gl::BYTE,
+    U16(&'data mut [u16]) = gl::UNSIGNED_SHORT,
+    I16(&'data mut [i16]) = gl::SHORT,
+    U32(&'data mut [u32]) = gl::UNSIGNED_INT,
+    I32(&'data mut [i32]) = gl::INT,
+    F16(&'data mut [u16]) = gl::HALF_FLOAT,
+    F32(&'data mut [f32]) = gl::FLOAT,
+    Packed5_6_5(&'data mut [u16]) = gl::UNSIGNED_SHORT_5_6_5,
+    Packed4_4_4_4(&'data mut [u16]) = gl::UNSIGNED_SHORT_4_4_4_4,
+    Packed5_5_5_1(&'data mut [u16]) = gl::UNSIGNED_SHORT_5_5_5_1,
+    Reverse2_10_10_10(&'data mut [u32]) = gl::UNSIGNED_INT_2_10_10_10_REV,
+    Reverse10F11F11F(&'data mut [u32]) = gl::UNSIGNED_INT_10F_11F_11F_REV,
+    Reverse5_9_9_9(&'data mut [u32]) = gl::UNSIGNED_INT_5_9_9_9_REV,
+    Packed24_8(&'data mut [u32]) = gl::UNSIGNED_INT_24_8,
+    F32Reverse24_8(&'data mut [F32Reverse24_8]) = gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
+}
+
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for ImageDataMut<'_> {}
+
+impl ImageDataMut<'_> {
+    /// Get the datatype of the elements, discarding the data.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::U8(_) => DataType::U8,
+            Self::I8(_) => DataType::I8,
+            Self::U16(_) => DataType::U16,
+            Self::I16(_) => DataType::I16,
+            Self::U32(_) => DataType::U32,
+            Self::I32(_) => DataType::I32,
+            Self::F16(_) => DataType::F16,
+            Self::F32(_) => DataType::F32,
+            Self::Packed5_6_5(_) => DataType::Packed5_6_5,
+            Self::Packed4_4_4_4(_) => DataType::Packed4_4_4_4,
+            Self::Packed5_5_5_1(_) => DataType::Packed5_5_5_1,
+            Self::Reverse2_10_10_10(_) => DataType::Reverse2_10_10_10,
+            Self::Reverse10F11F11F(_) => DataType::Reverse10F11F11F,
+            Self::Reverse5_9_9_9(_) => DataType::Reverse5_9_9_9,
+            Self::Packed24_8(_) => DataType::Packed24_8,
+            Self::F32Reverse24_8(_) => DataType::F32Reverse24_8,
+        }
+    }
+    /// Get a pointer to the beginning of the data, and it's length in bytes.
+    pub(crate) fn as_mut_bytes(&mut self) -> (*mut core::ffi::c_void, usize) {
+        fn raw<T>(slice: &mut [T]) -> (*mut core::ffi::c_void, usize) {
+            (slice.as_mut_ptr().cast(), core::mem::size_of_val(slice))
+        }
+        match self {
+            Self::U8(s) => raw(s),
+            Self::I8(s) => raw(s),
+            Self::U16(s)
+            | Self::F16(s)
+            | Self::Packed5_6_5(s)
+            | Self::Packed4_4_4_4(s)
+            | Self::Packed5_5_5_1(s) => raw(s),
+            Self::I16(s) => raw(s),
+            Self::U32(s)
+            | Self::Reverse2_10_10_10(s)
+            | Self::Reverse10F11F11F(s)
+            | Self::Reverse5_9_9_9(s)
+            | Self::Packed24_8(s) => raw(s),
+            Self::I32(s) => raw(s),
+            Self::F32(s) => raw(s),
+            Self::F32Reverse24_8(s) => raw(s),
         }
     }
 }
@@ -358,6 +949,18 @@ pub enum Swizzle {
 // Safety: is repr(u32) enum.
 unsafe impl crate::GLEnum for Swizzle {}
 
+/// A channel of the color returned by sampling a texture.
+#[repr(u32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TextureChannel {
+    Red = gl::TEXTURE_SWIZZLE_R,
+    Green = gl::TEXTURE_SWIZZLE_G,
+    Blue = gl::TEXTURE_SWIZZLE_B,
+    Alpha = gl::TEXTURE_SWIZZLE_A,
+}
+// Safety: is repr(u32) enum.
+unsafe impl crate::GLEnum for TextureChannel {}
+
 #[derive(Copy, Clone)]
 pub enum Filter {
     Nearest,
@@ -403,12 +1006,28 @@ pub type Texture2D = Texture<D2>;
 pub type Texture2DArray = Texture<D2Array>;
 pub type Texture3D = Texture<D3>;
 pub type TextureCube = Texture<Cube>;
+pub type Texture2DMultisample = Texture<D2Multisample>;
 
 /// An application-owned texture which does not currently have a dimensionality, properties,
 /// nor datastore. Bind it to a texture target in order to initialize the GL-internal datastructures.
 #[repr(transparent)]
 #[must_use = "dropping a gl handle leaks resources"]
 pub struct Stateless(pub(crate) NonZeroName);
+impl Stateless {
+    /// Assign a dimensionality without binding, deferring initialization to a later
+    /// [`Slot::bind`](crate::slot::texture::Slot::bind).
+    ///
+    /// Prefer [`Slot::initialize`](crate::slot::texture::Slot::initialize), which binds immediately.
+    ///
+    /// # Safety
+    /// The GL object does not exist until the first bind. The returned texture must be bound to the
+    /// slot of dimensionality `Dim` before being used in any other way, e.g. being attached to a
+    /// framebuffer or having its name passed to external GL code.
+    #[must_use = "dropping a gl handle leaks resources"]
+    pub unsafe fn assume_dimensionality<Dim: Dimensionality>(self) -> Texture<Dim> {
+        Texture(self.0, core::marker::PhantomData)
+    }
+}
 
 /// A texture who's state has been forgotten, for bulk deletions.
 #[repr(transparent)]
@@ -429,12 +1048,18 @@ impl<Dim: Dimensionality> From<Texture<Dim>> for DeletionToken {
 impl crate::sealed::Sealed for Stateless {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for Stateless {}
+unsafe impl crate::ThinGLObject for Stateless {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Texture;
+}
 impl crate::sealed::Sealed for DeletionToken {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for DeletionToken {}
+unsafe impl crate::ThinGLObject for DeletionToken {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Texture;
+}
 impl<Dim: Dimensionality> crate::sealed::Sealed for Texture<Dim> {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl<Dim: Dimensionality> crate::ThinGLObject for Texture<Dim> {}
+unsafe impl<Dim: Dimensionality> crate::ThinGLObject for Texture<Dim> {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::Texture;
+}
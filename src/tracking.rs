@@ -0,0 +1,88 @@
+//! Debug-only bookkeeping of live object names, enabled by the `debug-object-tracking` feature.
+//!
+//! The GL freely recycles deleted names, so a stale handle may silently refer to a
+//! different object. Every name handed out by `glGen*`/`glCreate*` is recorded here, and
+//! binding a name that has since been deleted panics. Names never recorded, e.g. those of objects
+//! created outside of this crate, are let through unchecked.
+//!
+//! Textures additionally remember the target they were first bound to, as binding a texture to a
+//! different target is an error.
+//!
+//! This is global rather than per-context, as names may be shared between contexts.
+use alloc::collections::BTreeMap;
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{
+    gl::types::{GLenum, GLuint},
+    sealed::ObjectKind,
+    ThinGLObject,
+};
+
+/// A spinlock around the set of live names - `no_std` has no `Mutex`, and contention is
+/// limited to threads juggling several contexts.
+///
+/// Each live name maps to the target it was first bound to, or zero if not yet bound or if the
+/// object kind has no fixed target. Deleted names map to `None` until recycled.
+struct Live {
+    locked: AtomicBool,
+    names: UnsafeCell<BTreeMap<(ObjectKind, GLuint), Option<GLenum>>>,
+}
+// Safety: `names` is only accessed while `locked` is held.
+unsafe impl Sync for Live {}
+
+static LIVE: Live = Live {
+    locked: AtomicBool::new(false),
+    names: UnsafeCell::new(BTreeMap::new()),
+};
+
+/// Run `f` with exclusive access to the live set.
+///
+/// `f` must not panic, else the lock is never released.
+fn with_live<R>(f: impl FnOnce(&mut BTreeMap<(ObjectKind, GLuint), Option<GLenum>>) -> R) -> R {
+    while LIVE
+        .locked
+        .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        core::hint::spin_loop();
+    }
+    // Safety: we hold the lock.
+    let result = f(unsafe { &mut *LIVE.names.get() });
+    LIVE.locked.store(false, Ordering::Release);
+    result
+}
+
+/// Record freshly generated names of `T`.
+pub(crate) fn track<T: ThinGLObject>(names: &[GLuint]) {
+    with_live(|live| live.extend(names.iter().map(|&name| ((T::KIND, name), Some(0)))));
+}
+/// Mark names of `T` which are about to be deleted.
+pub(crate) fn untrack<T: ThinGLObject>(names: &[GLuint]) {
+    with_live(|live| live.extend(names.iter().map(|&name| ((T::KIND, name), None))));
+}
+/// Panic if `object` was generated through this crate and has since been deleted.
+pub(crate) fn assert_live<T: ThinGLObject>(object: &T) {
+    let name = unsafe { object.name() }.get();
+    let deleted = with_live(|live| live.get(&(T::KIND, name)) == Some(&None));
+    assert!(!deleted, "use of deleted {:?} name {name}", T::KIND);
+}
+/// Record that the texture `name` is being bound to `target`, panicking if it was first bound to
+/// a different one.
+pub(crate) fn assert_texture_target(name: GLuint, target: GLenum) {
+    let first = with_live(|live| {
+        let first = live.get_mut(&(ObjectKind::Texture, name))?.as_mut()?;
+        if *first == 0 {
+            *first = target;
+        }
+        Some(*first)
+    });
+    if let Some(first) = first {
+        assert_eq!(
+            first, target,
+            "texture name {name} bound to target {target:#x}, but was initialized as {first:#x}"
+        );
+    }
+}
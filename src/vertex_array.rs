@@ -6,6 +6,7 @@ use super::{gl, NonZero, NonZeroName};
 ///
 /// For non-packed formats, this determines the number of `ty` typed items to read.
 /// For [packed](PackedIntegerAttribute) formats, this must be [`Components::Vec4`].
+#[derive(Copy, Clone)]
 #[repr(i32)]
 pub enum Components {
     Scalar = 1,
@@ -20,6 +21,7 @@ impl From<Components> for i32 {
 }
 
 /// One integer per component.
+#[derive(Copy, Clone)]
 #[repr(u32)]
 pub enum IntegerAttribute {
     U8 = gl::UNSIGNED_BYTE,
@@ -48,6 +50,7 @@ impl IntegerAttribute {
 unsafe impl crate::GLEnum for IntegerAttribute {}
 
 /// One float per component.
+#[derive(Copy, Clone)]
 #[repr(u32)]
 pub enum FloatingAttribute {
     F16 = gl::HALF_FLOAT,
@@ -73,6 +76,7 @@ impl FloatingAttribute {
 unsafe impl crate::GLEnum for FloatingAttribute {}
 
 /// A Single element representing four packed components.
+#[derive(Copy, Clone)]
 #[repr(u32)]
 pub enum PackedIntegerAttribute {
     /// LSB -> MSB, `[i10, i10, i10, i2]` packed signed integers.
@@ -95,6 +99,7 @@ impl PackedIntegerAttribute {
 unsafe impl crate::GLEnum for PackedIntegerAttribute {}
 
 /// Specifies the type and interpretation of component data.
+#[derive(Copy, Clone)]
 pub enum AttributeType {
     /// Fetch as integers, access in shader as integers.
     Integer(IntegerAttribute),
@@ -132,9 +137,26 @@ impl AttributeType {
             AttributeType::PackedScaled(ty) | AttributeType::PackedNormalized(ty) => ty.align_of(),
         }
     }
+    /// Whether this type packs all four components into a single value.
+    #[must_use]
+    pub fn is_packed(&self) -> bool {
+        matches!(
+            self,
+            AttributeType::PackedScaled(_) | AttributeType::PackedNormalized(_)
+        )
+    }
+    /// Panic if this is a packed type and `components` is not [`Components::Vec4`], as packed
+    /// types always hold four.
+    pub(crate) fn assert_components(&self, components: Components) {
+        assert!(
+            !self.is_packed() || matches!(components, Components::Vec4),
+            "packed attribute types require `Components::Vec4`"
+        );
+    }
 }
 
 /// Arguments to `glVertexAttrib[I]Pointer`.
+#[derive(Copy, Clone)]
 pub struct Attribute {
     /// The type of data to fetch from the array, as well as it's interpretation
     /// within the shader interface.
@@ -153,6 +175,120 @@ pub struct Attribute {
     pub offset: usize,
 }
 
+impl Attribute {
+    /// The size in bytes of a single value of this attribute.
+    #[must_use]
+    pub fn value_size(&self) -> usize {
+        let components = match self.components {
+            Components::Scalar => 1,
+            Components::Vec2 => 2,
+            Components::Vec3 => 3,
+            Components::Vec4 => 4,
+        };
+        if self.ty.is_packed() {
+            // Four components in one `u32`.
+            4
+        } else {
+            // The size of every non-packed type is equal to its alignment.
+            self.ty.align_of() * components
+        }
+    }
+    /// The spacing in bytes between consecutive values of this attribute, as used by the GL.
+    ///
+    /// This is [`Self::stride`] if given, otherwise the tightly packed [`Self::value_size`].
+    /// Useful to check that a layout matches the `size_of` a vertex struct.
+    #[must_use]
+    pub fn computed_stride(&self) -> usize {
+        self.stride.map_or_else(|| self.value_size(), NonZero::get)
+    }
+}
+
+/// The attributes of an interleaved vertex type `V`, as `(index, attribute)` pairs.
+///
+/// Used with [`GLHF::upload_mesh`](crate::GLHF::upload_mesh) to set up a vertex array fetching
+/// every attribute from a single buffer of `V`s.
+pub struct VertexLayout<'a, V> {
+    attributes: &'a [(u32, Attribute)],
+    _vertex: core::marker::PhantomData<fn() -> V>,
+}
+impl<'a, V: bytemuck::Pod> VertexLayout<'a, V> {
+    /// Describe the attributes of `V`.
+    ///
+    /// Usage:
+    /// ```
+    /// use glhf::vertex_array::{Attribute, Components, FloatingAttribute, VertexLayout};
+    /// #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+    /// #[repr(C)]
+    /// struct Vertex {
+    ///     pos: [f32; 3],
+    ///     uv: [f32; 2],
+    /// }
+    /// let stride = core::num::NonZero::new(core::mem::size_of::<Vertex>());
+    /// let attributes = [
+    ///     (
+    ///         0,
+    ///         Attribute {
+    ///             ty: FloatingAttribute::F32.into(),
+    ///             components: Components::Vec3,
+    ///             stride,
+    ///             offset: core::mem::offset_of!(Vertex, pos),
+    ///         },
+    ///     ),
+    ///     (
+    ///         1,
+    ///         Attribute {
+    ///             ty: FloatingAttribute::F32.into(),
+    ///             components: Components::Vec2,
+    ///             stride,
+    ///             offset: core::mem::offset_of!(Vertex, uv),
+    ///         },
+    ///     ),
+    /// ];
+    /// let layout = VertexLayout::<Vertex>::new(&attributes);
+    /// ```
+    ///
+    /// # Panics
+    /// * If an attribute's [`computed_stride`](Attribute::computed_stride) is not the size of `V`.
+    /// * If an attribute extends beyond the end of `V`.
+    #[must_use]
+    pub fn new(attributes: &'a [(u32, Attribute)]) -> Self {
+        let size = core::mem::size_of::<V>();
+        for (index, attribute) in attributes {
+            assert_eq!(
+                attribute.computed_stride(),
+                size,
+                "stride of attribute {index} should be the size of the vertex"
+            );
+            assert!(
+                attribute.offset + attribute.value_size() <= size,
+                "attribute {index} extends beyond the vertex"
+            );
+        }
+        Self {
+            attributes,
+            _vertex: core::marker::PhantomData,
+        }
+    }
+    /// The `(index, attribute)` pairs of this layout.
+    #[must_use]
+    pub fn attributes(&self) -> &'a [(u32, Attribute)] {
+        self.attributes
+    }
+}
+
+/// The state of a vertex attribute slot, as read back by
+/// [`Active::get_attribute`](crate::slot::vertex_array::Active::get_attribute).
+pub struct AttributeState {
+    /// Whether the attribute is fetched from its buffer during draws.
+    pub enabled: bool,
+    /// The layout of the attribute. `None` if the GL reports a layout this crate cannot express.
+    pub attribute: Option<Attribute>,
+    /// The instance divisor, zero if the attribute advances per-vertex.
+    pub divisor: u32,
+    /// The name of the buffer the attribute is fetched from, if any.
+    pub buffer: Option<NonZeroName>,
+}
+
 /// User-defined vertex array.
 ///
 /// A vertex array provides offsets, sizes, and types for the attributes fetched by the
@@ -164,4 +300,6 @@ pub struct VertexArray(pub(crate) NonZeroName);
 impl crate::sealed::Sealed for VertexArray {}
 // # Safety
 // Repr(transparent) over a NonZero<u32> (and some ZSTs), so can safely transmute.
-unsafe impl crate::ThinGLObject for VertexArray {}
+unsafe impl crate::ThinGLObject for VertexArray {
+    const KIND: crate::sealed::ObjectKind = crate::sealed::ObjectKind::VertexArray;
+}